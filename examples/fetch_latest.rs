@@ -1,4 +1,4 @@
-use discourse_api::DiscourseClient;
+use discourse_api_rs::DiscourseClient;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
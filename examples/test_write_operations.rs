@@ -1,4 +1,4 @@
-use discourse_api_rs::DiscourseClient;
+use discourse_api_rs::{CategoryId, DiscourseClient};
 use std::env;
 
 #[tokio::main]
@@ -6,7 +6,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let url = env::var("DISCOURSE_URL").expect("DISCOURSE_URL environment variable required");
     let api_key = env::var("DISCOURSE_API_KEY").expect("DISCOURSE_API_KEY environment variable required");
     let username = env::var("DISCOURSE_USERNAME").expect("DISCOURSE_USERNAME environment variable required");
-    let mut category_id = env::var("TEST_CATEGORY_ID").ok().and_then(|s| s.parse::<u64>().ok());
+    let mut category_id = env::var("TEST_CATEGORY_ID").ok().and_then(|s| s.parse::<u64>().ok()).map(CategoryId);
 
     let client = DiscourseClient::with_api_key(&url, &api_key, &username);
 
@@ -56,7 +56,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
                     // Test 3: Update the reply post
                     println!("\n3. Updating the reply post...");
-                    match client.update_post(reply_post_id, "This reply has been updated!").await {
+                    match client.update_post(reply_post_id, "This reply has been updated!", None).await {
                         Ok(_) => println!("✓ Updated post #{}", reply_post_id),
                         Err(e) => println!("✗ Failed to update: {:?}", e),
                     }
@@ -0,0 +1,163 @@
+use crate::client::{AuthType, DiscourseClient, ParseMode, RetryPolicy};
+use crate::error::Result;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::Client;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Duration;
+
+/// Builds a [`DiscourseClient`] with custom authentication and transport
+/// options.
+///
+/// When a preconfigured `reqwest::Client` is supplied through
+/// [`http_client`](Self::http_client) it is used as-is and the transport
+/// options on this builder (timeouts, user agent, headers, proxies) are
+/// ignored.
+pub struct DiscourseClientBuilder {
+    base_url: String,
+    auth: AuthType,
+    retry_policy: RetryPolicy,
+    parse_mode: ParseMode,
+    http_client: Option<Client>,
+    default_headers: HeaderMap,
+    #[cfg(not(target_arch = "wasm32"))]
+    timeout: Option<Duration>,
+    #[cfg(not(target_arch = "wasm32"))]
+    connect_timeout: Option<Duration>,
+    #[cfg(not(target_arch = "wasm32"))]
+    user_agent: Option<String>,
+    #[cfg(not(target_arch = "wasm32"))]
+    proxies: Vec<reqwest::Proxy>,
+}
+
+impl DiscourseClientBuilder {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            auth: AuthType::None,
+            retry_policy: RetryPolicy::default(),
+            parse_mode: ParseMode::default(),
+            http_client: None,
+            default_headers: HeaderMap::new(),
+            #[cfg(not(target_arch = "wasm32"))]
+            timeout: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            connect_timeout: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            user_agent: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            proxies: Vec::new(),
+        }
+    }
+
+    pub fn auth(mut self, auth: AuthType) -> Self {
+        self.auth = auth;
+        self
+    }
+
+    pub fn api_key(self, api_key: impl Into<String>, api_username: impl Into<String>) -> Self {
+        self.auth(AuthType::AdminKey {
+            api_key: api_key.into(),
+            api_username: api_username.into(),
+        })
+    }
+
+    pub fn user_api_key(self, user_api_key: impl Into<String>) -> Self {
+        self.auth(AuthType::UserKey {
+            user_api_key: user_api_key.into(),
+            user_api_client_id: None,
+        })
+    }
+
+    pub fn user_api_key_with_client_id(
+        self,
+        user_api_key: impl Into<String>,
+        user_api_client_id: impl Into<String>,
+    ) -> Self {
+        self.auth(AuthType::UserKey {
+            user_api_key: user_api_key.into(),
+            user_api_client_id: Some(user_api_client_id.into()),
+        })
+    }
+
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    pub fn parse_mode(mut self, parse_mode: ParseMode) -> Self {
+        self.parse_mode = parse_mode;
+        self
+    }
+
+    /// Reuses an existing `reqwest::Client`, e.g. to share a connection pool.
+    pub fn http_client(mut self, client: Client) -> Self {
+        self.http_client = Some(client);
+        self
+    }
+
+    pub fn default_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.default_headers.insert(name, value);
+        self
+    }
+
+    pub fn default_headers(mut self, headers: HeaderMap) -> Self {
+        self.default_headers.extend(headers);
+        self
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    pub fn build(self) -> Result<DiscourseClient> {
+        let client = match self.http_client {
+            Some(client) => client,
+            None => {
+                let mut builder = Client::builder().default_headers(self.default_headers);
+                #[cfg(not(target_arch = "wasm32"))]
+                {
+                    if let Some(timeout) = self.timeout {
+                        builder = builder.timeout(timeout);
+                    }
+                    if let Some(connect_timeout) = self.connect_timeout {
+                        builder = builder.connect_timeout(connect_timeout);
+                    }
+                    if let Some(user_agent) = self.user_agent {
+                        builder = builder.user_agent(user_agent);
+                    }
+                    for proxy in self.proxies {
+                        builder = builder.proxy(proxy);
+                    }
+                }
+                builder.build()?
+            }
+        };
+        Ok(DiscourseClient::from_parts(
+            self.base_url,
+            client,
+            self.auth,
+            self.retry_policy,
+        )
+        .with_parse_mode(self.parse_mode))
+    }
+}
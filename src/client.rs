@@ -1,26 +1,83 @@
-use crate::error::Result;
+use crate::builder::DiscourseClientBuilder;
+use crate::error::{Error, Result};
 use crate::types::*;
-use reqwest::Client;
+use futures_util::stream::{self, Stream, StreamExt, TryStreamExt};
+use reqwest::{Client, Method, StatusCode};
+use std::collections::HashMap;
+use std::time::Duration;
 
+/// Discourse serves at most this many posts per `/t/{id}/posts.json` call.
+const POST_CHUNK_SIZE: usize = 20;
+const DEFAULT_POST_CONCURRENCY: usize = 4;
+/// S3 rejects multipart parts smaller than 5 MiB, except for the last one.
+pub const DEFAULT_UPLOAD_PART_SIZE: u64 = 8 * 1024 * 1024;
+/// How many part URLs to ask Discourse to presign per request.
+const PRESIGN_BATCH_SIZE: usize = 10;
+
+#[derive(Clone)]
 pub enum AuthType {
     None,
     AdminKey { api_key: String, api_username: String },
     UserKey { user_api_key: String, user_api_client_id: Option<String> },
 }
 
+/// Controls how requests rejected with HTTP 429 are retried.
+///
+/// The wait between attempts comes from the `Retry-After` header, falling
+/// back to `extras.wait_seconds` in the Discourse error body and finally to
+/// exponential backoff capped at `max_wait`. When the server asks for a
+/// longer wait than `max_wait`, the request fails with
+/// `Error::RateLimited` right away instead of retrying too early.
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub max_wait: Duration,
+}
+
+impl RetryPolicy {
+    pub fn disabled() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            max_wait: Duration::from_secs(60),
+        }
+    }
+}
+
+/// How strictly responses are checked against the modeled types.
+///
+/// Major types such as `Topic` and `Post` keep unknown fields in `extra`
+/// and fall back to defaults for fields the server leaves out. `Strict`
+/// instead rejects a response that omits a required field, which is handy
+/// for catching API drift in tests. Optional fields, and fields the server
+/// only sends when set (such as `acted` in `actions_summary`), are never
+/// required.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParseMode {
+    #[default]
+    Lenient,
+    Strict,
+}
+
 pub struct DiscourseClient {
     base_url: String,
     client: Client,
     auth: AuthType,
+    retry_policy: RetryPolicy,
+    parse_mode: ParseMode,
 }
 
 impl DiscourseClient {
     pub fn new(base_url: impl Into<String>) -> Self {
-        Self {
-            base_url: base_url.into(),
-            client: Client::new(),
-            auth: AuthType::None,
-        }
+        Self::from_parts(base_url.into(), Client::new(), AuthType::None, RetryPolicy::default())
     }
 
     pub fn with_api_key(
@@ -28,49 +85,91 @@ impl DiscourseClient {
         api_key: impl Into<String>,
         api_username: impl Into<String>,
     ) -> Self {
-        Self {
-            base_url: base_url.into(),
-            client: Client::new(),
-            auth: AuthType::AdminKey {
-                api_key: api_key.into(),
-                api_username: api_username.into(),
-            },
-        }
+        let auth = AuthType::AdminKey {
+            api_key: api_key.into(),
+            api_username: api_username.into(),
+        };
+        Self::from_parts(base_url.into(), Client::new(), auth, RetryPolicy::default())
     }
 
     pub fn with_user_api_key(
         base_url: impl Into<String>,
         user_api_key: impl Into<String>,
     ) -> Self {
-        Self {
-            base_url: base_url.into(),
-            client: Client::new(),
-            auth: AuthType::UserKey {
-                user_api_key: user_api_key.into(),
-                user_api_client_id: None,
-            },
-        }
+        let auth = AuthType::UserKey {
+            user_api_key: user_api_key.into(),
+            user_api_client_id: None,
+        };
+        Self::from_parts(base_url.into(), Client::new(), auth, RetryPolicy::default())
     }
 
     pub fn with_user_api_key_and_client_id(
         base_url: impl Into<String>,
         user_api_key: impl Into<String>,
         user_api_client_id: impl Into<String>,
+    ) -> Self {
+        let auth = AuthType::UserKey {
+            user_api_key: user_api_key.into(),
+            user_api_client_id: Some(user_api_client_id.into()),
+        };
+        Self::from_parts(base_url.into(), Client::new(), auth, RetryPolicy::default())
+    }
+
+    pub fn builder(base_url: impl Into<String>) -> DiscourseClientBuilder {
+        DiscourseClientBuilder::new(base_url)
+    }
+
+    pub(crate) fn from_parts(
+        base_url: String,
+        client: Client,
+        auth: AuthType,
+        retry_policy: RetryPolicy,
     ) -> Self {
         Self {
-            base_url: base_url.into(),
-            client: Client::new(),
-            auth: AuthType::UserKey {
-                user_api_key: user_api_key.into(),
-                user_api_client_id: Some(user_api_client_id.into()),
-            },
+            base_url: base_url.trim_end_matches('/').to_string(),
+            client,
+            auth,
+            retry_policy,
+            parse_mode: ParseMode::default(),
         }
     }
 
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    pub fn with_parse_mode(mut self, parse_mode: ParseMode) -> Self {
+        self.parse_mode = parse_mode;
+        self
+    }
+
     fn build_url(&self, path: &str) -> String {
         format!("{}{}", self.base_url, path)
     }
 
+    /// Turns a `more_topics_url` style link (`/latest?page=1`) into a JSON
+    /// API path relative to `base_url`, which may live under a subfolder.
+    fn json_path(&self, url: &str) -> String {
+        let base_path = reqwest::Url::parse(&self.base_url)
+            .map(|base| base.path().trim_end_matches('/').to_string())
+            .unwrap_or_default();
+        let url = url.strip_prefix(base_path.as_str()).unwrap_or(url);
+        let (path, query) = match url.split_once('?') {
+            Some((path, query)) => (path, Some(query)),
+            None => (url, None),
+        };
+        let mut json_path = path.to_string();
+        if !json_path.ends_with(".json") {
+            json_path.push_str(".json");
+        }
+        if let Some(query) = query {
+            json_path.push('?');
+            json_path.push_str(query);
+        }
+        json_path
+    }
+
     fn add_auth_headers(&self, mut request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
         match &self.auth {
             AuthType::None => {},
@@ -87,20 +186,107 @@ impl DiscourseClient {
         request
     }
 
+    /// Sends a request, transparently retrying it while Discourse answers
+    /// with HTTP 429 and the retry budget allows. Requests whose body cannot
+    /// be cloned are sent once.
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn send(&self, request: reqwest::RequestBuilder) -> Result<reqwest::Response> {
+        let mut attempt = 0;
+        loop {
+            let retryable = if attempt < self.retry_policy.max_retries {
+                request.try_clone()
+            } else {
+                None
+            };
+            let Some(current) = retryable else {
+                return Ok(request.send().await?);
+            };
+            let response = current.send().await?;
+            if response.status() != StatusCode::TOO_MANY_REQUESTS {
+                return Ok(response);
+            }
+            let error = Error::from_response(response).await;
+            let requested = error
+                .wait_seconds()
+                .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok());
+            let wait = match requested {
+                Some(wait) if wait > self.retry_policy.max_wait => return Err(error),
+                Some(wait) => wait,
+                None => Duration::from_secs(1 << attempt.min(6)).min(self.retry_policy.max_wait),
+            };
+            tokio::time::sleep(wait).await;
+            attempt += 1;
+        }
+    }
+
+    /// There is no timer available without a browser runtime dependency, so
+    /// rate-limited requests are not retried on wasm.
+    #[cfg(target_arch = "wasm32")]
+    async fn send(&self, request: reqwest::RequestBuilder) -> Result<reqwest::Response> {
+        Ok(request.send().await?)
+    }
+
+    async fn check_status(&self, response: reqwest::Response) -> Result<reqwest::Response> {
+        if response.status().is_success() {
+            Ok(response)
+        } else {
+            Err(Error::from_response(response).await)
+        }
+    }
+
     async fn handle_response<T: serde::de::DeserializeOwned>(
         &self,
         response: reqwest::Response,
     ) -> Result<T> {
-        let status = response.status();
-        if !status.is_success() {
-            // Try to parse as Discourse error response
-            if let Ok(error_response) = response.json::<ErrorResponse>().await {
-                return Err(crate::error::Error::Api(error_response.errors.join(", ")));
-            }
-            return Err(crate::error::Error::Api(format!("HTTP {}", status)));
+        let response = self.check_status(response).await?;
+        if self.parse_mode == ParseMode::Lenient {
+            return Ok(response.json().await?);
         }
-        let data: T = response.json().await?;
-        Ok(data)
+        let raw: serde_json::Value = response.json().await?;
+        Ok(crate::strict::from_value(&raw)?)
+    }
+
+    /// Calls an endpoint this crate has no method for, with the client's
+    /// authentication, retries and error mapping. `path` is relative to the
+    /// base URL, e.g. `/admin/badges.json`. An empty response body is read
+    /// as JSON `null`, so `T` can be `()` or an `Option`. The client's
+    /// `ParseMode` applies as for the built-in endpoints.
+    pub async fn request<T: serde::de::DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        query: &[(&str, &str)],
+        body: Option<&serde_json::Value>,
+    ) -> Result<T> {
+        let url = self.build_url(path);
+        let mut request = self.add_auth_headers(self.client.request(method, &url));
+        if !query.is_empty() {
+            request = request.query(query);
+        }
+        if let Some(body) = body {
+            request = request.json(body);
+        }
+        let response = self.send(request).await?;
+        let bytes = self.check_status(response).await?.bytes().await?;
+        let raw = if bytes.iter().all(u8::is_ascii_whitespace) {
+            serde_json::Value::Null
+        } else {
+            serde_json::from_slice(&bytes)?
+        };
+        match self.parse_mode {
+            ParseMode::Lenient => Ok(serde_json::from_value(raw)?),
+            ParseMode::Strict => Ok(crate::strict::from_value(&raw)?),
+        }
+    }
+
+    pub async fn request_json(
+        &self,
+        method: Method,
+        path: &str,
+        query: &[(&str, &str)],
+        body: Option<&serde_json::Value>,
+    ) -> Result<serde_json::Value> {
+        self.request(method, path, query, body).await
     }
 
     pub async fn get_latest(&self) -> Result<LatestResponse> {
@@ -110,23 +296,57 @@ impl DiscourseClient {
     pub async fn get_latest_page(&self, page: u32) -> Result<LatestResponse> {
         let url = self.build_url(&format!("/latest.json?page={}", page));
         let request = self.add_auth_headers(self.client.get(&url));
-        let response = request.send().await?;
+        let response = self.send(request).await?;
         self.handle_response(response).await
     }
 
+    /// Streams every topic in a topic list, following `more_topics_url`
+    /// until the list is exhausted. `path` is the JSON path of the first
+    /// page, e.g. `/top.json?period=weekly`.
+    pub fn topic_list_stream(
+        &self,
+        path: impl Into<String>,
+    ) -> impl Stream<Item = Result<Topic>> + '_ {
+        stream::try_unfold(Some(path.into()), move |next| async move {
+            let Some(path) = next else {
+                return Ok::<_, Error>(None);
+            };
+            let url = self.build_url(&path);
+            let request = self.add_auth_headers(self.client.get(&url));
+            let response = self.send(request).await?;
+            let page: LatestResponse = self.handle_response(response).await?;
+            let topic_list = page.topic_list;
+            let next = match topic_list.more_topics_url {
+                Some(more) if !topic_list.topics.is_empty() => Some(self.json_path(&more)),
+                _ => None,
+            };
+            let topics = stream::iter(topic_list.topics.into_iter().map(Ok::<_, Error>));
+            Ok(Some((topics, next)))
+        })
+        .try_flatten()
+    }
+
+    pub fn latest_stream(&self) -> impl Stream<Item = Result<Topic>> + '_ {
+        self.topic_list_stream("/latest.json")
+    }
+
+    pub fn category_topics_stream(&self, category_id: CategoryId) -> impl Stream<Item = Result<Topic>> + '_ {
+        self.topic_list_stream(format!("/c/{}/l/latest.json", category_id))
+    }
+
     pub async fn get_categories(&self) -> Result<Vec<Category>> {
         let url = self.build_url("/categories.json");
         let request = self.add_auth_headers(self.client.get(&url));
-        let response = request.send().await?;
+        let response = self.send(request).await?;
         let data: CategoryList = self.handle_response(response).await?;
         Ok(data.category_list.categories)
     }
 
-    pub async fn get_topic(&self, topic_id: u64) -> Result<TopicResponse> {
+    pub async fn get_topic(&self, topic_id: TopicId) -> Result<TopicResponse> {
         self.get_topic_from_post(topic_id, None).await
     }
 
-    pub async fn get_topic_from_post(&self, topic_id: u64, after_post_number: Option<u32>) -> Result<TopicResponse> {
+    pub async fn get_topic_from_post(&self, topic_id: TopicId, after_post_number: Option<u32>) -> Result<TopicResponse> {
         let url = if let Some(post_num) = after_post_number {
             format!("/t/{}/{}.json?include_raw=1", topic_id, post_num)
         } else {
@@ -134,11 +354,11 @@ impl DiscourseClient {
         };
         let url = self.build_url(&url);
         let request = self.add_auth_headers(self.client.get(&url));
-        let response = request.send().await?;
+        let response = self.send(request).await?;
         self.handle_response(response).await
     }
 
-    pub async fn get_topic_posts(&self, topic_id: u64, post_ids: Option<Vec<u64>>) -> Result<TopicResponse> {
+    pub async fn get_topic_posts(&self, topic_id: TopicId, post_ids: Option<Vec<PostId>>) -> Result<TopicResponse> {
         let mut url = format!("/t/{}/posts.json?include_raw=1", topic_id);
         if let Some(ids) = post_ids {
             for id in ids {
@@ -147,152 +367,1378 @@ impl DiscourseClient {
         }
         let url = self.build_url(&url);
         let request = self.add_auth_headers(self.client.get(&url));
-        let response = request.send().await?;
+        let response = self.send(request).await?;
         self.handle_response(response).await
     }
 
-    pub async fn get_post(&self, post_id: u64) -> Result<Post> {
+    /// Fetches a topic together with every post in its post stream, not just
+    /// the first page that `get_topic` returns.
+    pub async fn get_full_topic(&self, topic_id: TopicId) -> Result<TopicResponse> {
+        self.get_full_topic_with_concurrency(topic_id, DEFAULT_POST_CONCURRENCY).await
+    }
+
+    pub async fn get_full_topic_with_concurrency(
+        &self,
+        topic_id: TopicId,
+        concurrency: usize,
+    ) -> Result<TopicResponse> {
+        let mut topic = self.get_topic(topic_id).await?;
+        let loaded = std::mem::take(&mut topic.post_stream.posts);
+        topic.post_stream.posts = self
+            .load_post_stream(topic_id, loaded, topic.post_stream.stream.clone(), concurrency)
+            .try_collect()
+            .await?;
+        Ok(topic)
+    }
+
+    /// Streams every post of a topic in order, fetching up to `concurrency`
+    /// batches of posts at a time.
+    pub fn topic_posts_stream(
+        &self,
+        topic_id: TopicId,
+        concurrency: usize,
+    ) -> impl Stream<Item = Result<Post>> + '_ {
+        stream::once(self.get_topic(topic_id))
+            .map_ok(move |topic| {
+                let PostStream { posts, stream } = topic.post_stream;
+                self.load_post_stream(topic_id, posts, stream, concurrency)
+            })
+            .try_flatten()
+    }
+
+    /// Walks `stream` in chunks, reusing already loaded posts and fetching
+    /// the rest by ID, and yields the posts in stream order.
+    fn load_post_stream(
+        &self,
+        topic_id: TopicId,
+        loaded: Vec<Post>,
+        stream: Vec<PostId>,
+        concurrency: usize,
+    ) -> impl Stream<Item = Result<Post>> + '_ {
+        let ids = if stream.is_empty() {
+            loaded.iter().map(|post| post.id).collect()
+        } else {
+            stream
+        };
+        let mut loaded: HashMap<PostId, Post> =
+            loaded.into_iter().map(|post| (post.id, post)).collect();
+        let chunks: Vec<_> = ids
+            .chunks(POST_CHUNK_SIZE)
+            .map(|chunk| {
+                let have: Vec<Post> = chunk.iter().filter_map(|id| loaded.remove(id)).collect();
+                (chunk.to_vec(), have)
+            })
+            .collect();
+
+        stream::iter(chunks)
+            .map(move |(chunk, have)| async move {
+                let missing: Vec<PostId> = chunk
+                    .iter()
+                    .filter(|id| !have.iter().any(|post| post.id == **id))
+                    .copied()
+                    .collect();
+                let mut posts: HashMap<PostId, Post> =
+                    have.into_iter().map(|post| (post.id, post)).collect();
+                if !missing.is_empty() {
+                    let fetched = self.get_topic_posts(topic_id, Some(missing)).await?;
+                    posts.extend(fetched.post_stream.posts.into_iter().map(|post| (post.id, post)));
+                }
+                let ordered: Vec<Result<Post>> =
+                    chunk.iter().filter_map(|id| posts.remove(id)).map(Ok).collect();
+                Ok::<_, Error>(stream::iter(ordered))
+            })
+            .buffered(concurrency.max(1))
+            .try_flatten()
+    }
+
+    pub async fn get_post(&self, post_id: PostId) -> Result<Post> {
         let url = self.build_url(&format!("/posts/{}.json", post_id));
         let request = self.add_auth_headers(self.client.get(&url));
-        let response = request.send().await?;
+        let response = self.send(request).await?;
         self.handle_response(response).await
     }
 
-    pub async fn get_category_topics(&self, category_id: u64) -> Result<LatestResponse> {
+    pub async fn get_category_topics(&self, category_id: CategoryId) -> Result<LatestResponse> {
         let url = self.build_url(&format!("/c/{}/l/latest.json", category_id));
         let request = self.add_auth_headers(self.client.get(&url));
-        let response = request.send().await?;
+        let response = self.send(request).await?;
         self.handle_response(response).await
     }
 
-    pub async fn get_user_channels(&self) -> Result<ChatChannelsResponse> {
-        let url = self.build_url("/chat/api/me/channels");
+    /// Bookmarks a post or topic, optionally with a reminder at `reminder_at`.
+    pub async fn create_bookmark(
+        &self,
+        bookmarkable: BookmarkableType,
+        reminder_at: Option<&Timestamp>,
+        name: Option<&str>,
+        auto_delete_preference: Option<BookmarkAutoDeletePreference>,
+    ) -> Result<CreateBookmarkResponse> {
+        let url = self.build_url("/bookmarks.json");
+        let request = self.add_auth_headers(self.client.post(&url));
+        let mut body = serde_json::json!({
+            "bookmarkable_type": bookmarkable.as_str(),
+            "bookmarkable_id": bookmarkable.id(),
+        });
+        if let Some(reminder_at) = reminder_at {
+            body["reminder_at"] = serde_json::json!(reminder_at);
+        }
+        if let Some(name) = name {
+            body["name"] = serde_json::json!(name);
+        }
+        if let Some(preference) = auto_delete_preference {
+            body["auto_delete_preference"] = serde_json::json!(preference);
+        }
+        let response = self.send(request.json(&body)).await?;
+        self.handle_response(response).await
+    }
+
+    /// Replaces a bookmark's reminder, name and auto delete preference.
+    pub async fn update_bookmark(
+        &self,
+        bookmark_id: u64,
+        reminder_at: Option<&Timestamp>,
+        name: Option<&str>,
+        auto_delete_preference: Option<BookmarkAutoDeletePreference>,
+    ) -> Result<()> {
+        let url = self.build_url(&format!("/bookmarks/{}.json", bookmark_id));
+        let request = self.add_auth_headers(self.client.put(&url));
+        let mut body = serde_json::json!({
+            "reminder_at": reminder_at,
+            "name": name,
+        });
+        if let Some(preference) = auto_delete_preference {
+            body["auto_delete_preference"] = serde_json::json!(preference);
+        }
+        let response = self.send(request.json(&body)).await?;
+        let _: serde_json::Value = self.handle_response(response).await?;
+        Ok(())
+    }
+
+    pub async fn delete_bookmark(&self, bookmark_id: u64) -> Result<()> {
+        let url = self.build_url(&format!("/bookmarks/{}.json", bookmark_id));
+        let request = self.add_auth_headers(self.client.delete(&url));
+        let response = self.send(request).await?;
+        let _: serde_json::Value = self.handle_response(response).await?;
+        Ok(())
+    }
+
+    pub async fn get_user_bookmarks(&self, username: &str) -> Result<UserBookmarkList> {
+        self.get_user_bookmarks_page(username, 0).await
+    }
+
+    pub async fn get_user_bookmarks_page(&self, username: &str, page: u32) -> Result<UserBookmarkList> {
+        let url = self.build_url(&format!("/u/{}/bookmarks.json?page={}", username, page));
+        let request = self.add_auth_headers(self.client.get(&url));
+        let response = self.send(request).await?;
+        let data: BookmarksResponse = self.handle_response(response).await?;
+        Ok(data.user_bookmark_list)
+    }
+
+    /// Runs a full page search. `query` can be a plain string or a
+    /// [`SearchQuery`](crate::SearchQuery).
+    pub async fn search(&self, query: impl std::fmt::Display) -> Result<SearchResult> {
+        self.search_page(query, 1).await
+    }
+
+    pub async fn search_page(&self, query: impl std::fmt::Display, page: u32) -> Result<SearchResult> {
+        let url = self.build_url("/search.json");
         let request = self.add_auth_headers(self.client.get(&url));
-        let response = request.send().await?;
+        let request = request.query(&[("q", query.to_string()), ("page", page.to_string())]);
+        let response = self.send(request).await?;
         self.handle_response(response).await
     }
 
-    pub async fn get_channel_messages(&self, channel_id: u64) -> Result<ChatMessagesResponse> {
-        let url = self.build_url(&format!("/chat/api/channels/{}/messages", channel_id));
+    pub async fn get_tags(&self) -> Result<TagsResponse> {
+        let url = self.build_url("/tags.json");
         let request = self.add_auth_headers(self.client.get(&url));
-        let response = request.send().await?;
+        let response = self.send(request).await?;
         self.handle_response(response).await
     }
 
-    pub async fn send_chat_message(
-        &self,
-        channel_id: u64,
-        message: &str,
-    ) -> Result<CreateMessageResponse> {
-        let url = self.build_url(&format!("/chat/{}", channel_id));
-        let mut request = self.add_auth_headers(self.client.post(&url));
+    pub async fn get_tag_topics(&self, tag: &str) -> Result<LatestResponse> {
+        let url = self.build_url(&format!("/tag/{}.json", tag));
+        let request = self.add_auth_headers(self.client.get(&url));
+        let response = self.send(request).await?;
+        self.handle_response(response).await
+    }
+
+    pub fn tag_topics_stream<'a>(&'a self, tag: &str) -> impl Stream<Item = Result<Topic>> + 'a {
+        self.topic_list_stream(format!("/tag/{}.json", tag))
+    }
+
+    pub async fn rename_tag(&self, tag: &str, new_name: &str) -> Result<()> {
+        let url = self.build_url(&format!("/tag/{}.json", tag));
+        let request = self.add_auth_headers(self.client.put(&url));
         let body = serde_json::json!({
-            "message": message,
+            "tag": {
+                "id": new_name,
+            }
         });
-        request = request.json(&body);
-        let response = request.send().await?;
-        self.handle_response(response).await
+        let response = self.send(request.json(&body)).await?;
+        let _: serde_json::Value = self.handle_response(response).await?;
+        Ok(())
     }
 
-    pub async fn create_topic(
+    pub async fn delete_tag(&self, tag: &str) -> Result<()> {
+        let url = self.build_url(&format!("/tag/{}.json", tag));
+        let request = self.add_auth_headers(self.client.delete(&url));
+        let response = self.send(request).await?;
+        self.check_status(response).await?;
+        Ok(())
+    }
+
+    pub async fn get_tag_groups(&self) -> Result<Vec<TagGroup>> {
+        let url = self.build_url("/tag_groups.json");
+        let request = self.add_auth_headers(self.client.get(&url));
+        let response = self.send(request).await?;
+        let data: TagGroupsResponse = self.handle_response(response).await?;
+        Ok(data.tag_groups)
+    }
+
+    pub async fn get_tag_group(&self, tag_group_id: u64) -> Result<TagGroup> {
+        let url = self.build_url(&format!("/tag_groups/{}.json", tag_group_id));
+        let request = self.add_auth_headers(self.client.get(&url));
+        let response = self.send(request).await?;
+        let data: TagGroupResponse = self.handle_response(response).await?;
+        Ok(data.tag_group)
+    }
+
+    pub async fn create_tag_group(
         &self,
-        title: &str,
-        raw: &str,
-        category_id: Option<u64>,
-    ) -> Result<CreatePostResponse> {
-        let url = self.build_url("/posts.json");
+        name: &str,
+        tag_names: &[&str],
+        one_per_topic: bool,
+    ) -> Result<TagGroup> {
+        let url = self.build_url("/tag_groups.json");
         let request = self.add_auth_headers(self.client.post(&url));
-        let mut body = serde_json::json!({
-            "title": title,
-            "raw": raw,
+        let body = serde_json::json!({
+            "name": name,
+            "tag_names": tag_names,
+            "one_per_topic": one_per_topic,
         });
-        if let Some(cat_id) = category_id {
-            body["category"] = serde_json::json!(cat_id);
-        }
-        let response = request.json(&body).send().await?;
+        let response = self.send(request.json(&body)).await?;
+        let data: TagGroupResponse = self.handle_response(response).await?;
+        Ok(data.tag_group)
+    }
+
+    pub async fn delete_tag_group(&self, tag_group_id: u64) -> Result<()> {
+        let url = self.build_url(&format!("/tag_groups/{}.json", tag_group_id));
+        let request = self.add_auth_headers(self.client.delete(&url));
+        let response = self.send(request).await?;
+        self.check_status(response).await?;
+        Ok(())
+    }
+
+    pub async fn get_user(&self, username: &str) -> Result<UserDetail> {
+        let url = self.build_url(&format!("/u/{}.json", username));
+        let request = self.add_auth_headers(self.client.get(&url));
+        let response = self.send(request).await?;
+        let data: UserResponse = self.handle_response(response).await?;
+        Ok(data.user)
+    }
+
+    pub async fn get_user_summary(&self, username: &str) -> Result<UserSummaryResponse> {
+        let url = self.build_url(&format!("/u/{}/summary.json", username));
+        let request = self.add_auth_headers(self.client.get(&url));
+        let response = self.send(request).await?;
         self.handle_response(response).await
     }
 
-    pub async fn create_post(
+    /// Lists a user's activity, newest first. An empty `filter` returns every
+    /// action type.
+    pub async fn get_user_actions(
         &self,
-        topic_id: u64,
-        raw: &str,
-        reply_to_post_number: Option<u32>,
-    ) -> Result<CreatePostResponse> {
-        let url = self.build_url("/posts.json");
-        let mut request = self.add_auth_headers(self.client.post(&url));
-        let mut body = serde_json::json!({
-            "raw": raw,
-            "topic_id": topic_id,
-        });
-        if let Some(reply_to) = reply_to_post_number {
-            body["reply_to_post_number"] = serde_json::json!(reply_to);
+        username: &str,
+        filter: &[UserActionType],
+        offset: u32,
+    ) -> Result<Vec<UserAction>> {
+        let url = self.build_url("/user_actions.json");
+        let request = self.add_auth_headers(self.client.get(&url));
+        let mut query = vec![
+            ("username", username.to_string()),
+            ("offset", offset.to_string()),
+        ];
+        if !filter.is_empty() {
+            let ids: Vec<String> = filter.iter().map(|t| u32::from(*t).to_string()).collect();
+            query.push(("filter", ids.join(",")));
         }
-        request = request.json(&body);
-        let response = request.send().await?;
+        let response = self.send(request.query(&query)).await?;
+        let data: UserActionsResponse = self.handle_response(response).await?;
+        Ok(data.user_actions)
+    }
+
+    pub async fn get_directory_items(
+        &self,
+        period: DirectoryPeriod,
+        order: DirectoryOrder,
+        page: u32,
+    ) -> Result<DirectoryResponse> {
+        let url = self.build_url(&format!(
+            "/directory_items.json?period={}&order={}&page={}",
+            period.as_str(),
+            order.as_str(),
+            page
+        ));
+        let request = self.add_auth_headers(self.client.get(&url));
+        let response = self.send(request).await?;
         self.handle_response(response).await
     }
 
-    pub async fn update_post(&self, post_id: u64, raw: &str) -> Result<()> {
-        let url = self.build_url(&format!("/posts/{}.json", post_id));
+    /// Creates a user account. Discourse reports validation failures with a
+    /// successful status, so they are turned into `Error::Unprocessable`.
+    pub async fn create_user(&self, user: &NewUser) -> Result<CreateUserResponse> {
+        let url = self.build_url("/users.json");
+        let request = self.add_auth_headers(self.client.post(&url));
+        let response = self.send(request.json(user)).await?;
+        let data: CreateUserResponse = self.handle_response(response).await?;
+        if !data.success {
+            return Err(Error::Unprocessable {
+                errors: vec![data.message.clone()],
+                response: Some(ErrorResponse {
+                    errors: vec![data.message],
+                    error_type: None,
+                    extras: None,
+                }),
+            });
+        }
+        Ok(data)
+    }
+
+    async fn admin_user_action(
+        &self,
+        user_id: UserId,
+        action: &str,
+        body: serde_json::Value,
+    ) -> Result<serde_json::Value> {
+        let url = self.build_url(&format!("/admin/users/{}/{}.json", user_id, action));
         let request = self.add_auth_headers(self.client.put(&url));
+        let response = self.send(request.json(&body)).await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn activate_user(&self, user_id: UserId) -> Result<()> {
+        self.admin_user_action(user_id, "activate", serde_json::json!({})).await?;
+        Ok(())
+    }
+
+    pub async fn deactivate_user(&self, user_id: UserId) -> Result<()> {
+        self.admin_user_action(user_id, "deactivate", serde_json::json!({})).await?;
+        Ok(())
+    }
+
+    /// Suspends a user until `suspend_until`; use a far future date for a
+    /// permanent suspension.
+    pub async fn suspend_user(
+        &self,
+        user_id: UserId,
+        suspend_until: &Timestamp,
+        reason: &str,
+    ) -> Result<Suspension> {
         let body = serde_json::json!({
-            "post": {
-                "raw": raw,
-            }
+            "suspend_until": suspend_until,
+            "reason": reason,
         });
-        let response = request.json(&body).send().await?;
-        let _: serde_json::Value = self.handle_response(response).await?;
+        let data = self.admin_user_action(user_id, "suspend", body).await?;
+        let data: SuspensionResponse = serde_json::from_value(data)?;
+        Ok(data.suspension)
+    }
+
+    pub async fn unsuspend_user(&self, user_id: UserId) -> Result<()> {
+        self.admin_user_action(user_id, "unsuspend", serde_json::json!({})).await?;
         Ok(())
     }
 
-    pub async fn delete_post(&self, post_id: u64) -> Result<()> {
-        let url = self.build_url(&format!("/posts/{}.json", post_id));
-        let request = self.add_auth_headers(self.client.delete(&url));
-        let response = request.send().await?;
-        let status = response.status();
-        if !status.is_success() {
-            if let Ok(error_response) = response.json::<crate::types::ErrorResponse>().await {
-                return Err(crate::error::Error::Api(error_response.errors.join(", ")));
-            }
-            return Err(crate::error::Error::Api(format!("HTTP {}", status)));
+    /// Silences a user, indefinitely when `silenced_till` is `None`.
+    pub async fn silence_user(
+        &self,
+        user_id: UserId,
+        silenced_till: Option<&Timestamp>,
+        reason: &str,
+    ) -> Result<Silence> {
+        let mut body = serde_json::json!({
+            "reason": reason,
+        });
+        if let Some(till) = silenced_till {
+            body["silenced_till"] = serde_json::json!(till);
         }
+        let data = self.admin_user_action(user_id, "silence", body).await?;
+        let data: SilenceResponse = serde_json::from_value(data)?;
+        Ok(data.silence)
+    }
+
+    pub async fn unsilence_user(&self, user_id: UserId) -> Result<()> {
+        self.admin_user_action(user_id, "unsilence", serde_json::json!({})).await?;
         Ok(())
     }
 
-    pub async fn like_post(&self, post_id: u64) -> Result<()> {
-        let url = self.build_url("/post_actions");
-        let request = self.add_auth_headers(self.client.post(&url));
+    pub async fn grant_trust_level(&self, user_id: UserId, level: u32) -> Result<()> {
         let body = serde_json::json!({
-            "id": post_id,
-            "post_action_type_id": 2,
+            "level": level,
         });
-        let response = request.json(&body).send().await?;
-        let _: serde_json::Value = self.handle_response(response).await?;
+        self.admin_user_action(user_id, "trust_level", body).await?;
+        Ok(())
+    }
+
+    pub async fn grant_admin(&self, user_id: UserId) -> Result<()> {
+        self.admin_user_action(user_id, "grant_admin", serde_json::json!({})).await?;
+        Ok(())
+    }
+
+    pub async fn revoke_admin(&self, user_id: UserId) -> Result<()> {
+        self.admin_user_action(user_id, "revoke_admin", serde_json::json!({})).await?;
+        Ok(())
+    }
+
+    pub async fn grant_moderator(&self, user_id: UserId) -> Result<()> {
+        self.admin_user_action(user_id, "grant_moderation", serde_json::json!({})).await?;
+        Ok(())
+    }
+
+    pub async fn revoke_moderator(&self, user_id: UserId) -> Result<()> {
+        self.admin_user_action(user_id, "revoke_moderation", serde_json::json!({})).await?;
         Ok(())
     }
 
-    pub async fn unlike_post(&self, post_id: u64) -> Result<()> {
-        let url = self.build_url(&format!("/post_actions/{}", post_id));
+    pub async fn anonymize_user(&self, user_id: UserId) -> Result<AnonymizeUserResponse> {
+        let data = self.admin_user_action(user_id, "anonymize", serde_json::json!({})).await?;
+        Ok(serde_json::from_value(data)?)
+    }
+
+    pub async fn delete_user(
+        &self,
+        user_id: UserId,
+        delete_posts: bool,
+        block_email: bool,
+        block_ip: bool,
+    ) -> Result<DeleteUserResponse> {
+        let url = self.build_url(&format!("/admin/users/{}.json", user_id));
         let request = self.add_auth_headers(self.client.delete(&url));
-        let response = request
-            .query(&[("post_action_type_id", "2")])
-            .send()
-            .await?;
-        let status = response.status();
-        if !status.is_success() {
-            if let Ok(error_response) = response.json::<crate::types::ErrorResponse>().await {
-                return Err(crate::error::Error::Api(error_response.errors.join(", ")));
+        let body = serde_json::json!({
+            "delete_posts": delete_posts,
+            "block_email": block_email,
+            "block_ip": block_ip,
+        });
+        let response = self.send(request.json(&body)).await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn get_groups(&self) -> Result<GroupsResponse> {
+        self.get_groups_page(0).await
+    }
+
+    pub async fn get_groups_page(&self, page: u32) -> Result<GroupsResponse> {
+        let url = self.build_url(&format!("/groups.json?page={}", page));
+        let request = self.add_auth_headers(self.client.get(&url));
+        let response = self.send(request).await?;
+        self.handle_response(response).await
+    }
+
+    /// Maps every visible group id to its name, e.g. to resolve the
+    /// `primary_group_id` and `flair_group_id` of posters.
+    pub async fn get_group_names(&self) -> Result<HashMap<u64, String>> {
+        let mut names = HashMap::new();
+        let mut page = 0;
+        loop {
+            let data = self.get_groups_page(page).await?;
+            if data.groups.is_empty() {
+                break;
+            }
+            names.extend(data.groups.into_iter().map(|group| (group.id, group.name)));
+            if names.len() >= data.total_rows_groups as usize {
+                break;
             }
-            return Err(crate::error::Error::Api(format!("HTTP {}", status)));
+            page += 1;
         }
-        Ok(())
+        Ok(names)
     }
 
-    pub async fn get_notifications(&self) -> Result<NotificationsResponse> {
-        let url = self.build_url("/notifications.json");
+    pub async fn get_group(&self, name: &str) -> Result<Group> {
+        let url = self.build_url(&format!("/groups/{}.json", name));
         let request = self.add_auth_headers(self.client.get(&url));
-        let response = request.send().await?;
-        self.handle_response(response).await
+        let response = self.send(request).await?;
+        let data: GroupResponse = self.handle_response(response).await?;
+        Ok(data.group)
     }
-}
+
+    pub async fn get_group_members(
+        &self,
+        name: &str,
+        offset: u32,
+        limit: u32,
+    ) -> Result<GroupMembersResponse> {
+        let url = self.build_url(&format!(
+            "/groups/{}/members.json?offset={}&limit={}",
+            name, offset, limit
+        ));
+        let request = self.add_auth_headers(self.client.get(&url));
+        let response = self.send(request).await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn add_group_members(
+        &self,
+        group_id: u64,
+        members: &GroupMembers,
+    ) -> Result<AddGroupMembersResponse> {
+        let url = self.build_url(&format!("/groups/{}/members.json", group_id));
+        let request = self.add_auth_headers(self.client.put(&url));
+        let response = self.send(request.json(&group_members_body(members))).await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn remove_group_members(&self, group_id: u64, members: &GroupMembers) -> Result<()> {
+        let url = self.build_url(&format!("/groups/{}/members.json", group_id));
+        let request = self.add_auth_headers(self.client.delete(&url));
+        let response = self.send(request.json(&group_members_body(members))).await?;
+        let _: serde_json::Value = self.handle_response(response).await?;
+        Ok(())
+    }
+
+    pub async fn add_group_owners(&self, group_id: u64, usernames: &[&str]) -> Result<()> {
+        let url = self.build_url(&format!("/admin/groups/{}/owners.json", group_id));
+        let request = self.add_auth_headers(self.client.put(&url));
+        let response = self.send(request.json(&group_owners_body(usernames))).await?;
+        let _: serde_json::Value = self.handle_response(response).await?;
+        Ok(())
+    }
+
+    pub async fn remove_group_owners(&self, group_id: u64, usernames: &[&str]) -> Result<()> {
+        let url = self.build_url(&format!("/admin/groups/{}/owners.json", group_id));
+        let request = self.add_auth_headers(self.client.delete(&url));
+        let response = self.send(request.json(&group_owners_body(usernames))).await?;
+        let _: serde_json::Value = self.handle_response(response).await?;
+        Ok(())
+    }
+
+    pub async fn create_group(&self, settings: &GroupSettings) -> Result<Group> {
+        let url = self.build_url("/admin/groups.json");
+        let request = self.add_auth_headers(self.client.post(&url));
+        let body = serde_json::json!({
+            "group": settings,
+        });
+        let response = self.send(request.json(&body)).await?;
+        let data: BasicGroupResponse = self.handle_response(response).await?;
+        Ok(data.basic_group)
+    }
+
+    pub async fn update_group(&self, group_id: u64, settings: &GroupSettings) -> Result<()> {
+        let url = self.build_url(&format!("/groups/{}.json", group_id));
+        let request = self.add_auth_headers(self.client.put(&url));
+        let body = serde_json::json!({
+            "group": settings,
+        });
+        let response = self.send(request.json(&body)).await?;
+        let _: serde_json::Value = self.handle_response(response).await?;
+        Ok(())
+    }
+
+    pub async fn delete_group(&self, group_id: u64) -> Result<()> {
+        let url = self.build_url(&format!("/admin/groups/{}.json", group_id));
+        let request = self.add_auth_headers(self.client.delete(&url));
+        let response = self.send(request).await?;
+        self.check_status(response).await?;
+        Ok(())
+    }
+
+    /// Uploads a file from memory. Works on every target, including wasm.
+    pub async fn upload_file(
+        &self,
+        bytes: Vec<u8>,
+        filename: &str,
+        upload_type: UploadType,
+    ) -> Result<Upload> {
+        let url = self.build_url("/uploads.json");
+        let request = self.add_auth_headers(self.client.post(&url));
+        let file = reqwest::multipart::Part::bytes(bytes).file_name(filename.to_string());
+        let form = reqwest::multipart::Form::new()
+            .text("type", upload_type.as_str())
+            .text("synchronous", "true")
+            .part("file", file);
+        let response = self.send(request.multipart(form)).await?;
+        self.handle_response(response).await
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn upload_file_from_path(
+        &self,
+        path: impl AsRef<std::path::Path>,
+        upload_type: UploadType,
+    ) -> Result<Upload> {
+        let path = path.as_ref();
+        let bytes = tokio::fs::read(path).await?;
+        let filename = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "upload".to_string());
+        self.upload_file(bytes, &filename, upload_type).await
+    }
+
+    /// Uploads a file straight to the external store (S3) with a single
+    /// presigned PUT, then registers it with Discourse.
+    pub async fn upload_file_presigned(
+        &self,
+        bytes: Vec<u8>,
+        file_name: &str,
+        upload_type: UploadType,
+    ) -> Result<Upload> {
+        let url = self.build_url("/uploads/generate-presigned-put.json");
+        let request = self.add_auth_headers(self.client.post(&url));
+        let body = serde_json::json!({
+            "type": upload_type.as_str(),
+            "file_name": file_name,
+            "file_size": bytes.len(),
+        });
+        let response = self.send(request.json(&body)).await?;
+        let presigned: PresignedPut = self.handle_response(response).await?;
+
+        let mut request = self.client.put(&presigned.url);
+        for (name, value) in &presigned.signed_headers {
+            request = request.header(name, value);
+        }
+        let response = request.body(bytes).send().await?;
+        self.check_status(response).await?;
+
+        let url = self.build_url("/uploads/complete-external-upload.json");
+        let request = self.add_auth_headers(self.client.post(&url));
+        let body = serde_json::json!({
+            "unique_identifier": presigned.unique_identifier,
+        });
+        let response = self.send(request.json(&body)).await?;
+        self.handle_response(response).await
+    }
+
+    /// Uploads a large file through the external multipart flow in one go.
+    /// Use the `*_multipart_upload` methods directly to be able to resume
+    /// after a failure.
+    pub async fn upload_large_file<F>(
+        &self,
+        bytes: &[u8],
+        file_name: &str,
+        upload_type: UploadType,
+        progress: F,
+    ) -> Result<Upload>
+    where
+        F: FnMut(UploadProgress),
+    {
+        let file_size = bytes.len() as u64;
+        let mut state = self
+            .start_multipart_upload(file_name, file_size, upload_type, DEFAULT_UPLOAD_PART_SIZE)
+            .await?;
+        self.upload_multipart_parts(&mut state, bytes, progress).await?;
+        self.complete_multipart_upload(&state).await
+    }
+
+    pub async fn start_multipart_upload(
+        &self,
+        file_name: &str,
+        file_size: u64,
+        upload_type: UploadType,
+        part_size: u64,
+    ) -> Result<MultipartUploadState> {
+        let url = self.build_url("/uploads/create-multipart.json");
+        let request = self.add_auth_headers(self.client.post(&url));
+        let body = serde_json::json!({
+            "upload_type": upload_type.as_str(),
+            "file_name": file_name,
+            "file_size": file_size,
+        });
+        let response = self.send(request.json(&body)).await?;
+        let created: CreateMultipartResponse = self.handle_response(response).await?;
+        Ok(MultipartUploadState {
+            unique_identifier: created.unique_identifier,
+            external_upload_identifier: created.external_upload_identifier,
+            key: created.key,
+            file_name: file_name.to_string(),
+            file_size,
+            part_size: part_size.max(1),
+            completed_parts: Vec::new(),
+        })
+    }
+
+    /// Uploads every part not yet recorded in `state.completed_parts`,
+    /// recording each one as it finishes. On error the state reflects what
+    /// was uploaded, so calling this again with the same bytes resumes.
+    pub async fn upload_multipart_parts<F>(
+        &self,
+        state: &mut MultipartUploadState,
+        bytes: &[u8],
+        mut progress: F,
+    ) -> Result<()>
+    where
+        F: FnMut(UploadProgress),
+    {
+        if bytes.len() as u64 != state.file_size {
+            return Err(Error::InvalidInput(format!(
+                "expected {} bytes for {}, got {}",
+                state.file_size,
+                state.file_name,
+                bytes.len()
+            )));
+        }
+        let total_parts = state.total_parts();
+        let pending: Vec<u32> = (1..=total_parts)
+            .filter(|part_number| !state.is_part_completed(*part_number))
+            .collect();
+
+        for batch in pending.chunks(PRESIGN_BATCH_SIZE) {
+            let url = self.build_url("/uploads/batch-presign-multipart-parts.json");
+            let request = self.add_auth_headers(self.client.post(&url));
+            let body = serde_json::json!({
+                "part_numbers": batch,
+                "unique_identifier": state.unique_identifier,
+            });
+            let response = self.send(request.json(&body)).await?;
+            let presigned: PresignedPartsResponse = self.handle_response(response).await?;
+
+            for part_number in batch {
+                let part_url = presigned
+                    .presigned_urls
+                    .get(&part_number.to_string())
+                    .ok_or_else(|| {
+                        Error::UnexpectedResponse(format!("no presigned URL for part {}", part_number))
+                    })?;
+                let (start, end) = state.part_range(*part_number);
+                let chunk = bytes[start as usize..end as usize].to_vec();
+                let response = self.client.put(part_url).body(chunk).send().await?;
+                let response = self.check_status(response).await?;
+                let etag = response
+                    .headers()
+                    .get(reqwest::header::ETAG)
+                    .and_then(|value| value.to_str().ok())
+                    .ok_or_else(|| {
+                        Error::UnexpectedResponse(format!("no ETag returned for part {}", part_number))
+                    })?;
+                state.completed_parts.push(CompletedPart {
+                    part_number: *part_number,
+                    etag: etag.to_string(),
+                });
+                progress(UploadProgress {
+                    bytes_uploaded: state.bytes_uploaded(),
+                    total_bytes: state.file_size,
+                    parts_completed: state.completed_parts.len() as u32,
+                    total_parts,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn complete_multipart_upload(&self, state: &MultipartUploadState) -> Result<Upload> {
+        let mut parts = state.completed_parts.clone();
+        parts.sort_by_key(|part| part.part_number);
+        let url = self.build_url("/uploads/complete-multipart.json");
+        let request = self.add_auth_headers(self.client.post(&url));
+        let body = serde_json::json!({
+            "unique_identifier": state.unique_identifier,
+            "parts": parts,
+        });
+        let response = self.send(request.json(&body)).await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn abort_multipart_upload(&self, state: &MultipartUploadState) -> Result<()> {
+        let url = self.build_url("/uploads/abort-multipart.json");
+        let request = self.add_auth_headers(self.client.post(&url));
+        let body = serde_json::json!({
+            "external_upload_identifier": state.external_upload_identifier,
+        });
+        let response = self.send(request.json(&body)).await?;
+        let _: serde_json::Value = self.handle_response(response).await?;
+        Ok(())
+    }
+
+    pub async fn get_user_channels(&self) -> Result<ChatChannelsResponse> {
+        let url = self.build_url("/chat/api/me/channels");
+        let request = self.add_auth_headers(self.client.get(&url));
+        let response = self.send(request).await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn get_channel_messages(&self, channel_id: ChannelId) -> Result<ChatMessagesResponse> {
+        let url = self.build_url(&format!("/chat/api/channels/{}/messages", channel_id));
+        let request = self.add_auth_headers(self.client.get(&url));
+        let response = self.send(request).await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn send_chat_message(
+        &self,
+        channel_id: ChannelId,
+        message: &str,
+    ) -> Result<CreateMessageResponse> {
+        let url = self.build_url(&format!("/chat/{}", channel_id));
+        let mut request = self.add_auth_headers(self.client.post(&url));
+        let body = serde_json::json!({
+            "message": message,
+        });
+        request = request.json(&body);
+        let response = self.send(request).await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn create_topic(
+        &self,
+        title: &str,
+        raw: &str,
+        category_id: Option<CategoryId>,
+    ) -> Result<CreatePostResponse> {
+        self.create_topic_with_tags(title, raw, category_id, &[]).await
+    }
+
+    pub async fn create_topic_with_tags(
+        &self,
+        title: &str,
+        raw: &str,
+        category_id: Option<CategoryId>,
+        tags: &[&str],
+    ) -> Result<CreatePostResponse> {
+        let url = self.build_url("/posts.json");
+        let request = self.add_auth_headers(self.client.post(&url));
+        let mut body = serde_json::json!({
+            "title": title,
+            "raw": raw,
+        });
+        if let Some(cat_id) = category_id {
+            body["category"] = serde_json::json!(cat_id);
+        }
+        if !tags.is_empty() {
+            body["tags"] = serde_json::json!(tags);
+        }
+        let response = self.send(request.json(&body)).await?;
+        self.handle_response(response).await
+    }
+
+    /// Starts a personal message. `recipients` may mix usernames and group
+    /// names.
+    pub async fn send_private_message(
+        &self,
+        recipients: &[&str],
+        title: &str,
+        raw: &str,
+    ) -> Result<CreatePostResponse> {
+        let url = self.build_url("/posts.json");
+        let request = self.add_auth_headers(self.client.post(&url));
+        let body = serde_json::json!({
+            "title": title,
+            "raw": raw,
+            "archetype": "private_message",
+            "target_recipients": recipients.join(","),
+        });
+        let response = self.send(request.json(&body)).await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn get_private_messages(
+        &self,
+        username: &str,
+        folder: PrivateMessageFolder,
+    ) -> Result<LatestResponse> {
+        let url = self.build_url(&format!("/topics/{}/{}.json", folder.as_str(), username));
+        let request = self.add_auth_headers(self.client.get(&url));
+        let response = self.send(request).await?;
+        self.handle_response(response).await
+    }
+
+    pub fn private_messages_stream<'a>(
+        &'a self,
+        username: &str,
+        folder: PrivateMessageFolder,
+    ) -> impl Stream<Item = Result<Topic>> + 'a {
+        self.topic_list_stream(format!("/topics/{}/{}.json", folder.as_str(), username))
+    }
+
+    pub async fn get_group_private_messages(
+        &self,
+        username: &str,
+        group_name: &str,
+    ) -> Result<LatestResponse> {
+        let url = self.build_url(&format!(
+            "/topics/private-messages-group/{}/{}.json",
+            username, group_name
+        ));
+        let request = self.add_auth_headers(self.client.get(&url));
+        let response = self.send(request).await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn invite_to_private_message(&self, topic_id: TopicId, username: &str) -> Result<()> {
+        let url = self.build_url(&format!("/t/{}/invite.json", topic_id));
+        let request = self.add_auth_headers(self.client.post(&url));
+        let body = serde_json::json!({
+            "user": username,
+        });
+        let response = self.send(request.json(&body)).await?;
+        let _: serde_json::Value = self.handle_response(response).await?;
+        Ok(())
+    }
+
+    pub async fn invite_group_to_private_message(&self, topic_id: TopicId, group_name: &str) -> Result<()> {
+        let url = self.build_url(&format!("/t/{}/invite-group.json", topic_id));
+        let request = self.add_auth_headers(self.client.post(&url));
+        let body = serde_json::json!({
+            "group": group_name,
+        });
+        let response = self.send(request.json(&body)).await?;
+        let _: serde_json::Value = self.handle_response(response).await?;
+        Ok(())
+    }
+
+    pub async fn remove_private_message_participant(&self, topic_id: TopicId, username: &str) -> Result<()> {
+        let url = self.build_url(&format!("/t/{}/remove-allowed-user.json", topic_id));
+        let request = self.add_auth_headers(self.client.put(&url));
+        let body = serde_json::json!({
+            "username": username,
+        });
+        let response = self.send(request.json(&body)).await?;
+        let _: serde_json::Value = self.handle_response(response).await?;
+        Ok(())
+    }
+
+    pub async fn remove_private_message_group(&self, topic_id: TopicId, group_name: &str) -> Result<()> {
+        let url = self.build_url(&format!("/t/{}/remove-allowed-group.json", topic_id));
+        let request = self.add_auth_headers(self.client.put(&url));
+        let body = serde_json::json!({
+            "name": group_name,
+        });
+        let response = self.send(request.json(&body)).await?;
+        let _: serde_json::Value = self.handle_response(response).await?;
+        Ok(())
+    }
+
+    /// Toggles a topic status flag and returns the topic as it looks
+    /// afterwards. `until` only applies to pinning.
+    pub async fn update_topic_status(
+        &self,
+        topic_id: TopicId,
+        status: TopicStatus,
+        enabled: bool,
+        until: Option<&Timestamp>,
+    ) -> Result<TopicResponse> {
+        let url = self.build_url(&format!("/t/{}/status.json", topic_id));
+        let request = self.add_auth_headers(self.client.put(&url));
+        let mut body = serde_json::json!({
+            "status": status.as_str(),
+            "enabled": enabled,
+        });
+        if let Some(until) = until {
+            body["until"] = serde_json::json!(until);
+        }
+        let response = self.send(request.json(&body)).await?;
+        let _: serde_json::Value = self.handle_response(response).await?;
+        self.get_topic(topic_id).await
+    }
+
+    pub async fn close_topic(&self, topic_id: TopicId) -> Result<TopicResponse> {
+        self.update_topic_status(topic_id, TopicStatus::Closed, true, None).await
+    }
+
+    pub async fn open_topic(&self, topic_id: TopicId) -> Result<TopicResponse> {
+        self.update_topic_status(topic_id, TopicStatus::Closed, false, None).await
+    }
+
+    pub async fn archive_topic(&self, topic_id: TopicId) -> Result<TopicResponse> {
+        self.update_topic_status(topic_id, TopicStatus::Archived, true, None).await
+    }
+
+    pub async fn unarchive_topic(&self, topic_id: TopicId) -> Result<TopicResponse> {
+        self.update_topic_status(topic_id, TopicStatus::Archived, false, None).await
+    }
+
+    pub async fn pin_topic(&self, topic_id: TopicId, until: Option<&Timestamp>) -> Result<TopicResponse> {
+        self.update_topic_status(topic_id, TopicStatus::Pinned, true, until).await
+    }
+
+    pub async fn pin_topic_globally(&self, topic_id: TopicId, until: Option<&Timestamp>) -> Result<TopicResponse> {
+        self.update_topic_status(topic_id, TopicStatus::PinnedGlobally, true, until).await
+    }
+
+    pub async fn unpin_topic(&self, topic_id: TopicId) -> Result<TopicResponse> {
+        self.update_topic_status(topic_id, TopicStatus::Pinned, false, None).await
+    }
+
+    pub async fn unlist_topic(&self, topic_id: TopicId) -> Result<TopicResponse> {
+        self.update_topic_status(topic_id, TopicStatus::Visible, false, None).await
+    }
+
+    pub async fn list_topic(&self, topic_id: TopicId) -> Result<TopicResponse> {
+        self.update_topic_status(topic_id, TopicStatus::Visible, true, None).await
+    }
+
+    /// Renames, recategorizes and/or retags a topic. Fields left as `None`
+    /// are not changed.
+    pub async fn update_topic(
+        &self,
+        topic_id: TopicId,
+        title: Option<&str>,
+        category_id: Option<CategoryId>,
+        tags: Option<&[&str]>,
+    ) -> Result<BasicTopic> {
+        let url = self.build_url(&format!("/t/-/{}.json", topic_id));
+        let request = self.add_auth_headers(self.client.put(&url));
+        let mut body = serde_json::json!({});
+        if let Some(title) = title {
+            body["title"] = serde_json::json!(title);
+        }
+        if let Some(cat_id) = category_id {
+            body["category_id"] = serde_json::json!(cat_id);
+        }
+        if let Some(tags) = tags {
+            body["tags"] = serde_json::json!(tags);
+        }
+        let response = self.send(request.json(&body)).await?;
+        let data: BasicTopicResponse = self.handle_response(response).await?;
+        Ok(data.basic_topic)
+    }
+
+    /// Moves posts out of a topic, either into a brand new topic or onto the
+    /// end of an existing one, and returns the destination topic.
+    pub async fn move_posts(
+        &self,
+        topic_id: TopicId,
+        post_ids: &[PostId],
+        destination: PostDestination,
+    ) -> Result<TopicResponse> {
+        let url = self.build_url(&format!("/t/{}/move-posts.json", topic_id));
+        let request = self.add_auth_headers(self.client.post(&url));
+        let mut body = serde_json::json!({
+            "post_ids": post_ids,
+        });
+        match &destination {
+            PostDestination::NewTopic { title, category_id, tags } => {
+                body["title"] = serde_json::json!(title);
+                if let Some(cat_id) = category_id {
+                    body["category_id"] = serde_json::json!(cat_id);
+                }
+                if !tags.is_empty() {
+                    body["tags"] = serde_json::json!(tags);
+                }
+            }
+            PostDestination::ExistingTopic { topic_id } => {
+                body["destination_topic_id"] = serde_json::json!(topic_id);
+            }
+        }
+        let response = self.send(request.json(&body)).await?;
+        let data: MovePostsResponse = self.handle_response(response).await?;
+        let destination_id = match destination {
+            PostDestination::ExistingTopic { topic_id } => topic_id,
+            PostDestination::NewTopic { .. } => topic_id_from_url(&data.url).ok_or_else(|| {
+                Error::UnexpectedResponse(format!("cannot find topic id in {}", data.url))
+            })?,
+        };
+        self.get_topic(destination_id).await
+    }
+
+    pub async fn create_post(
+        &self,
+        topic_id: TopicId,
+        raw: &str,
+        reply_to_post_number: Option<u32>,
+    ) -> Result<CreatePostResponse> {
+        let url = self.build_url("/posts.json");
+        let mut request = self.add_auth_headers(self.client.post(&url));
+        let mut body = serde_json::json!({
+            "raw": raw,
+            "topic_id": topic_id,
+        });
+        if let Some(reply_to) = reply_to_post_number {
+            body["reply_to_post_number"] = serde_json::json!(reply_to);
+        }
+        request = request.json(&body);
+        let response = self.send(request).await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn update_post(
+        &self,
+        post_id: PostId,
+        raw: &str,
+        edit_reason: Option<&str>,
+    ) -> Result<Post> {
+        let url = self.build_url(&format!("/posts/{}.json", post_id));
+        let request = self.add_auth_headers(self.client.put(&url));
+        let mut body = serde_json::json!({
+            "post": {
+                "raw": raw,
+            }
+        });
+        if let Some(reason) = edit_reason {
+            body["post"]["edit_reason"] = serde_json::json!(reason);
+        }
+        let response = self.send(request.json(&body)).await?;
+        let updated: PostResponse = self.handle_response(response).await?;
+        Ok(updated.post)
+    }
+
+    pub async fn get_post_revision(&self, post_id: PostId, revision: u32) -> Result<PostRevision> {
+        let url = self.build_url(&format!("/posts/{}/revisions/{}.json", post_id, revision));
+        let request = self.add_auth_headers(self.client.get(&url));
+        let response = self.send(request).await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn get_latest_revision(&self, post_id: PostId) -> Result<PostRevision> {
+        let url = self.build_url(&format!("/posts/{}/revisions/latest.json", post_id));
+        let request = self.add_auth_headers(self.client.get(&url));
+        let response = self.send(request).await?;
+        self.handle_response(response).await
+    }
+
+    /// Restores the post body, title and category from `revision`, creating
+    /// a new revision on top of the history.
+    pub async fn revert_post_to_revision(&self, post_id: PostId, revision: u32) -> Result<Post> {
+        let url = self.build_url(&format!("/posts/{}/revisions/{}/revert", post_id, revision));
+        let request = self.add_auth_headers(self.client.put(&url));
+        let response = self.send(request).await?;
+        let reverted: PostResponse = self.handle_response(response).await?;
+        Ok(reverted.post)
+    }
+
+    /// Hides a revision from non-staff users. Staff only.
+    pub async fn hide_post_revision(&self, post_id: PostId, revision: u32) -> Result<()> {
+        let url = self.build_url(&format!("/posts/{}/revisions/{}/hide", post_id, revision));
+        let request = self.add_auth_headers(self.client.put(&url));
+        let response = self.send(request).await?;
+        self.check_status(response).await?;
+        Ok(())
+    }
+
+    pub async fn show_post_revision(&self, post_id: PostId, revision: u32) -> Result<()> {
+        let url = self.build_url(&format!("/posts/{}/revisions/{}/show", post_id, revision));
+        let request = self.add_auth_headers(self.client.put(&url));
+        let response = self.send(request).await?;
+        self.check_status(response).await?;
+        Ok(())
+    }
+
+    pub async fn delete_post(&self, post_id: PostId) -> Result<()> {
+        let url = self.build_url(&format!("/posts/{}.json", post_id));
+        let request = self.add_auth_headers(self.client.delete(&url));
+        let response = self.send(request).await?;
+        self.check_status(response).await?;
+        Ok(())
+    }
+
+    /// Deletes a post and returns it in its deleted state. Returns `None`
+    /// when there is nothing left the caller can see: after a
+    /// `force_destroy`, when deleting a first post removed its topic, or
+    /// when deleted posts are hidden from non-staff users.
+    pub async fn delete_post_with_options(
+        &self,
+        post_id: PostId,
+        force_destroy: bool,
+    ) -> Result<Option<Post>> {
+        let url = self.build_url(&format!("/posts/{}.json", post_id));
+        let mut request = self.add_auth_headers(self.client.delete(&url));
+        if force_destroy {
+            request = request.query(&[("force_destroy", "true")]);
+        }
+        let response = self.send(request).await?;
+        self.check_status(response).await?;
+        if force_destroy {
+            return Ok(None);
+        }
+        match self.get_post(post_id).await {
+            Ok(post) => Ok(Some(post)),
+            Err(error) if error.is_not_found() => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    pub async fn recover_post(&self, post_id: PostId) -> Result<Post> {
+        let url = self.build_url(&format!("/posts/{}/recover.json", post_id));
+        let request = self.add_auth_headers(self.client.put(&url));
+        let response = self.send(request).await?;
+        self.handle_response(response).await
+    }
+
+    /// Turns a post into a wiki or back. Like the other post setters below,
+    /// this refetches the post afterwards, so the result may already include
+    /// edits made by others in between.
+    pub async fn set_wiki(&self, post_id: PostId, wiki: bool) -> Result<Post> {
+        let body = serde_json::json!({ "wiki": wiki });
+        self.update_post_attribute(post_id, "wiki", &body).await
+    }
+
+    pub async fn set_post_type(&self, post_id: PostId, post_type: PostType) -> Result<Post> {
+        let body = serde_json::json!({ "post_type": post_type });
+        self.update_post_attribute(post_id, "post_type", &body).await
+    }
+
+    /// Locks a post so that only staff can edit it.
+    pub async fn lock_post(&self, post_id: PostId, locked: bool) -> Result<Post> {
+        let body = serde_json::json!({ "locked": locked });
+        self.update_post_attribute(post_id, "locked", &body).await
+    }
+
+    /// Reassigns a post to another user. The post may live in any topic;
+    /// its topic is looked up first, and the post is refetched afterwards.
+    pub async fn change_post_owner(&self, post_id: PostId, username: &str) -> Result<Post> {
+        let post = self.get_post(post_id).await?;
+        let url = self.build_url(&format!("/t/{}/change-owner.json", post.topic_id));
+        let request = self.add_auth_headers(self.client.post(&url));
+        let body = serde_json::json!({
+            "username": username,
+            "post_ids": [post_id],
+        });
+        let response = self.send(request.json(&body)).await?;
+        self.check_status(response).await?;
+        self.get_post(post_id).await
+    }
+
+    /// Updates one attribute and then fetches the post again; the two
+    /// requests are not atomic.
+    async fn update_post_attribute(
+        &self,
+        post_id: PostId,
+        attribute: &str,
+        body: &serde_json::Value,
+    ) -> Result<Post> {
+        let url = self.build_url(&format!("/posts/{}/{}", post_id, attribute));
+        let request = self.add_auth_headers(self.client.put(&url));
+        let response = self.send(request.json(body)).await?;
+        self.check_status(response).await?;
+        self.get_post(post_id).await
+    }
+
+    pub async fn like_post(&self, post_id: PostId) -> Result<()> {
+        self.act_on_post(post_id, PostActionType::Like, None).await?;
+        Ok(())
+    }
+
+    pub async fn unlike_post(&self, post_id: PostId) -> Result<()> {
+        self.undo_post_action(post_id, PostActionType::Like).await?;
+        Ok(())
+    }
+
+    /// Likes or flags a post and returns the post with its updated action
+    /// summary. `message` is shown to the user or moderators for
+    /// `NotifyUser`, `NotifyModerators` and `Illegal` flags.
+    pub async fn act_on_post(
+        &self,
+        post_id: PostId,
+        action: PostActionType,
+        message: Option<&str>,
+    ) -> Result<Post> {
+        let url = self.build_url("/post_actions.json");
+        let request = self.add_auth_headers(self.client.post(&url));
+        let mut body = serde_json::json!({
+            "id": post_id,
+            "post_action_type_id": action.id(),
+        });
+        if let Some(message) = message {
+            body["message"] = serde_json::json!(message);
+        }
+        let response = self.send(request.json(&body)).await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn undo_post_action(&self, post_id: PostId, action: PostActionType) -> Result<Post> {
+        let url = self.build_url(&format!("/post_actions/{}.json", post_id));
+        let request = self.add_auth_headers(self.client.delete(&url));
+        let request = request.query(&[("post_action_type_id", action.id())]);
+        let response = self.send(request).await?;
+        self.handle_response(response).await
+    }
+
+    /// Lists the users who took `action` on a post, e.g. who liked it.
+    pub async fn get_post_action_users(
+        &self,
+        post_id: PostId,
+        action: PostActionType,
+    ) -> Result<PostActionUsersResponse> {
+        let url = self.build_url(&format!(
+            "/post_action_users.json?id={}&post_action_type_id={}",
+            post_id,
+            action.id()
+        ));
+        let request = self.add_auth_headers(self.client.get(&url));
+        let response = self.send(request).await?;
+        self.handle_response(response).await
+    }
+
+    pub async fn get_notifications(&self) -> Result<NotificationsResponse> {
+        self.get_notifications_page(NotificationFilter::All, 0).await
+    }
+
+    pub async fn get_notifications_page(
+        &self,
+        filter: NotificationFilter,
+        offset: u32,
+    ) -> Result<NotificationsResponse> {
+        let url = self.build_url("/notifications.json");
+        let request = self.add_auth_headers(self.client.get(&url));
+        let mut query = vec![("offset", offset.to_string())];
+        if filter != NotificationFilter::All {
+            query.push(("filter", filter.as_str().to_string()));
+        }
+        let response = self.send(request.query(&query)).await?;
+        self.handle_response(response).await
+    }
+
+    /// The short list shown in the user menu, unread and high priority
+    /// notifications first.
+    pub async fn get_recent_notifications(&self, limit: u32) -> Result<NotificationsResponse> {
+        let url = self.build_url("/notifications.json");
+        let request = self.add_auth_headers(self.client.get(&url));
+        let query = [("recent", "true".to_string()), ("limit", limit.to_string())];
+        let response = self.send(request.query(&query)).await?;
+        self.handle_response(response).await
+    }
+
+    /// Fetches the page after `previous` by following its
+    /// `load_more_notifications` link. Returns `None` once a page comes back
+    /// empty.
+    pub async fn load_more_notifications(
+        &self,
+        previous: &NotificationsResponse,
+    ) -> Result<Option<NotificationsResponse>> {
+        let more = match &previous.load_more_notifications {
+            Some(more) if !previous.notifications.is_empty() => more,
+            _ => return Ok(None),
+        };
+        let url = self.build_url(&self.json_path(more));
+        let request = self.add_auth_headers(self.client.get(&url));
+        let response = self.send(request).await?;
+        let page: NotificationsResponse = self.handle_response(response).await?;
+        if page.notifications.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(page))
+    }
+
+    /// Marks a single notification as read, or all of them when `id` is
+    /// `None`.
+    pub async fn mark_notifications_read(&self, id: Option<u64>) -> Result<()> {
+        let url = self.build_url("/notifications/mark-read.json");
+        let mut request = self.add_auth_headers(self.client.put(&url));
+        if let Some(id) = id {
+            request = request.json(&serde_json::json!({ "id": id }));
+        }
+        let response = self.send(request).await?;
+        self.check_status(response).await?;
+        Ok(())
+    }
+}
+
+fn group_members_body(members: &GroupMembers) -> serde_json::Value {
+    match members {
+        GroupMembers::Usernames(usernames) => serde_json::json!({ "usernames": usernames.join(",") }),
+        GroupMembers::Emails(emails) => serde_json::json!({ "emails": emails.join(",") }),
+    }
+}
+
+fn group_owners_body(usernames: &[&str]) -> serde_json::Value {
+    serde_json::json!({ "group": { "usernames": usernames.join(",") } })
+}
+
+/// Extracts the topic id from a relative topic URL like `/t/some-slug/123`.
+fn topic_id_from_url(url: &str) -> Option<TopicId> {
+    let mut segments = url.split('/').skip_while(|segment| *segment != "t").skip(1);
+    let first = segments.next()?;
+    first
+        .parse()
+        .ok()
+        .or_else(|| segments.next()?.parse().ok())
+        .map(TopicId)
+}
+
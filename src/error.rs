@@ -1,3 +1,5 @@
+use crate::types::ErrorResponse;
+use reqwest::StatusCode;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -8,8 +10,165 @@ pub enum Error {
     #[error("JSON parsing failed: {0}")]
     Json(#[from] serde_json::Error),
 
-    #[error("API error: {0}")]
-    Api(String),
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Resource not found{}", describe(.response))]
+    NotFound { response: Option<ErrorResponse> },
+
+    #[error("Access denied (HTTP {status}){}", describe(.response))]
+    InvalidAccess {
+        status: StatusCode,
+        response: Option<ErrorResponse>,
+    },
+
+    #[error("Rate limited{}", describe(.response))]
+    RateLimited {
+        wait_seconds: Option<f64>,
+        response: Option<ErrorResponse>,
+    },
+
+    #[error("Unprocessable request: {}", .errors.join(", "))]
+    Unprocessable {
+        errors: Vec<String>,
+        response: Option<ErrorResponse>,
+    },
+
+    #[error("Server error (HTTP {status}){}", describe(.response))]
+    Server {
+        status: StatusCode,
+        response: Option<ErrorResponse>,
+    },
+
+    #[error("API error (HTTP {status}){}", describe(.response))]
+    Api {
+        status: StatusCode,
+        response: Option<ErrorResponse>,
+    },
+
+    #[error("Unexpected response: {0}")]
+    UnexpectedResponse(String),
+
+    #[error("Invalid input: {0}")]
+    InvalidInput(String),
+}
+
+fn describe(response: &Option<ErrorResponse>) -> String {
+    match response {
+        Some(response) if !response.errors.is_empty() => format!(": {}", response.errors.join(", ")),
+        _ => String::new(),
+    }
+}
+
+impl Error {
+    /// Builds the error for a non-successful response, keeping the status
+    /// code and whatever Discourse put in its JSON error body.
+    pub(crate) async fn from_response(response: reqwest::Response) -> Self {
+        let status = response.status();
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse::<f64>().ok());
+        let response = response.json::<ErrorResponse>().await.ok();
+        Self::from_parts(status, retry_after, response)
+    }
+
+    pub(crate) fn from_parts(
+        status: StatusCode,
+        retry_after: Option<f64>,
+        response: Option<ErrorResponse>,
+    ) -> Self {
+        let error_type = response.as_ref().and_then(|r| r.error_type.as_deref());
+        match status {
+            StatusCode::NOT_FOUND => Error::NotFound { response },
+            StatusCode::TOO_MANY_REQUESTS => {
+                let wait_seconds = retry_after.or_else(|| {
+                    response
+                        .as_ref()
+                        .and_then(|r| r.extras.as_ref())
+                        .and_then(|extras| extras.wait_seconds)
+                });
+                Error::RateLimited { wait_seconds, response }
+            }
+            StatusCode::UNPROCESSABLE_ENTITY => Error::Unprocessable {
+                errors: response.as_ref().map(|r| r.errors.clone()).unwrap_or_default(),
+                response,
+            },
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => {
+                Error::InvalidAccess { status, response }
+            }
+            _ if error_type == Some("invalid_access") => Error::InvalidAccess { status, response },
+            _ if status.is_server_error() => Error::Server { status, response },
+            _ => Error::Api { status, response },
+        }
+    }
+
+    /// HTTP status of the failed response, if the error came from Discourse.
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            Error::Request(e) => e.status(),
+            Error::Json(_) | Error::Io(_) | Error::UnexpectedResponse(_) | Error::InvalidInput(_) => {
+                None
+            }
+            Error::NotFound { .. } => Some(StatusCode::NOT_FOUND),
+            Error::RateLimited { .. } => Some(StatusCode::TOO_MANY_REQUESTS),
+            Error::Unprocessable { .. } => Some(StatusCode::UNPROCESSABLE_ENTITY),
+            Error::InvalidAccess { status, .. }
+            | Error::Server { status, .. }
+            | Error::Api { status, .. } => Some(*status),
+        }
+    }
+
+    /// The parsed Discourse error body, when the server sent one.
+    pub fn error_response(&self) -> Option<&ErrorResponse> {
+        match self {
+            Error::NotFound { response }
+            | Error::InvalidAccess { response, .. }
+            | Error::RateLimited { response, .. }
+            | Error::Unprocessable { response, .. }
+            | Error::Server { response, .. }
+            | Error::Api { response, .. } => response.as_ref(),
+            Error::Request(_)
+            | Error::Json(_)
+            | Error::Io(_)
+            | Error::UnexpectedResponse(_)
+            | Error::InvalidInput(_) => None,
+        }
+    }
+
+    /// Discourse's machine readable `error_type`, e.g. `not_found` or
+    /// `invalid_access`.
+    pub fn error_type(&self) -> Option<&str> {
+        self.error_response()?.error_type.as_deref()
+    }
+
+    /// Human readable messages from the Discourse error body.
+    pub fn errors(&self) -> &[String] {
+        self.error_response()
+            .map(|response| response.errors.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Seconds Discourse asked us to wait before retrying a rate-limited request.
+    pub fn wait_seconds(&self) -> Option<f64> {
+        match self {
+            Error::RateLimited { wait_seconds, .. } => *wait_seconds,
+            _ => None,
+        }
+    }
+
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, Error::NotFound { .. })
+    }
+
+    pub fn is_invalid_access(&self) -> bool {
+        matches!(self, Error::InvalidAccess { .. })
+    }
+
+    pub fn is_rate_limited(&self) -> bool {
+        matches!(self, Error::RateLimited { .. })
+    }
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
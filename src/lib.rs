@@ -1,12 +1,18 @@
+mod builder;
 mod client;
 mod error;
+mod search;
+mod strict;
 mod types;
 
 #[cfg(feature = "wasm")]
 mod wasm;
 
-pub use client::DiscourseClient;
+pub use builder::DiscourseClientBuilder;
+pub use client::{AuthType, DiscourseClient, ParseMode, RetryPolicy, DEFAULT_UPLOAD_PART_SIZE};
 pub use error::{Error, Result};
+pub use reqwest::Method;
+pub use search::{SearchIn, SearchOrder, SearchQuery, SearchStatus};
 pub use types::*;
 
 #[cfg(feature = "wasm")]
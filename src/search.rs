@@ -0,0 +1,185 @@
+use std::fmt;
+
+/// Builds a Discourse search query out of a free text term and the advanced
+/// filters the search page understands.
+///
+/// ```
+/// use discourse_api_rs::{SearchOrder, SearchQuery, SearchStatus};
+///
+/// let query = SearchQuery::new("upgrade failed")
+///     .user("sam")
+///     .category("support")
+///     .tags(&["docker"])
+///     .status(SearchStatus::Open)
+///     .order(SearchOrder::Latest);
+///
+/// assert_eq!(
+///     query.to_string(),
+///     "upgrade failed @sam #support tags:docker status:open order:latest"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchQuery {
+    term: String,
+    filters: Vec<String>,
+}
+
+impl SearchQuery {
+    pub fn new(term: impl Into<String>) -> Self {
+        Self {
+            term: term.into(),
+            filters: Vec::new(),
+        }
+    }
+
+    /// Only posts by `username` (`@username`).
+    pub fn user(self, username: &str) -> Self {
+        self.filter(format!("@{}", username))
+    }
+
+    /// Only topics in the category with this slug (`#slug`).
+    pub fn category(self, slug: &str) -> Self {
+        self.filter(format!("#{}", slug))
+    }
+
+    /// Only topics in a subcategory (`#parent:child`).
+    pub fn subcategory(self, parent_slug: &str, slug: &str) -> Self {
+        self.filter(format!("#{}:{}", parent_slug, slug))
+    }
+
+    /// Topics tagged with any of `tags` (`tags:a,b`).
+    pub fn tags(self, tags: &[&str]) -> Self {
+        self.filter(format!("tags:{}", tags.join(",")))
+    }
+
+    /// Topics tagged with all of `tags` (`tags:a+b`).
+    pub fn all_tags(self, tags: &[&str]) -> Self {
+        self.filter(format!("tags:{}", tags.join("+")))
+    }
+
+    pub fn within(self, scope: SearchIn) -> Self {
+        self.filter(format!("in:{}", scope.as_str()))
+    }
+
+    pub fn status(self, status: SearchStatus) -> Self {
+        self.filter(format!("status:{}", status.as_str()))
+    }
+
+    /// Posts created before a date, e.g. `2024-01-31`.
+    pub fn before(self, date: &str) -> Self {
+        self.filter(format!("before:{}", date))
+    }
+
+    /// Posts created after a date, e.g. `2024-01-01`.
+    pub fn after(self, date: &str) -> Self {
+        self.filter(format!("after:{}", date))
+    }
+
+    pub fn order(self, order: SearchOrder) -> Self {
+        self.filter(format!("order:{}", order.as_str()))
+    }
+
+    /// Appends a raw filter this builder has no helper for, e.g. `min_posts:5`.
+    pub fn filter(mut self, filter: impl Into<String>) -> Self {
+        self.filters.push(filter.into());
+        self
+    }
+}
+
+impl fmt::Display for SearchQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts = std::iter::once(self.term.as_str())
+            .chain(self.filters.iter().map(String::as_str))
+            .filter(|part| !part.is_empty());
+        for (i, part) in parts.enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            f.write_str(part)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchIn {
+    Bookmarks,
+    Likes,
+    Posted,
+    Created,
+    Watching,
+    Tracking,
+    Title,
+    First,
+    Pinned,
+    Wiki,
+    Seen,
+    Unseen,
+    Messages,
+}
+
+impl SearchIn {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchIn::Bookmarks => "bookmarks",
+            SearchIn::Likes => "likes",
+            SearchIn::Posted => "posted",
+            SearchIn::Created => "created",
+            SearchIn::Watching => "watching",
+            SearchIn::Tracking => "tracking",
+            SearchIn::Title => "title",
+            SearchIn::First => "first",
+            SearchIn::Pinned => "pinned",
+            SearchIn::Wiki => "wiki",
+            SearchIn::Seen => "seen",
+            SearchIn::Unseen => "unseen",
+            SearchIn::Messages => "messages",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchStatus {
+    Open,
+    Closed,
+    Public,
+    Archived,
+    NoReplies,
+    SingleUser,
+}
+
+impl SearchStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchStatus::Open => "open",
+            SearchStatus::Closed => "closed",
+            SearchStatus::Public => "public",
+            SearchStatus::Archived => "archived",
+            SearchStatus::NoReplies => "noreplies",
+            SearchStatus::SingleUser => "single_user",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchOrder {
+    Latest,
+    LatestTopic,
+    Oldest,
+    Likes,
+    Views,
+    Read,
+}
+
+impl SearchOrder {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchOrder::Latest => "latest",
+            SearchOrder::LatestTopic => "latest_topic",
+            SearchOrder::Oldest => "oldest",
+            SearchOrder::Likes => "likes",
+            SearchOrder::Views => "views",
+            SearchOrder::Read => "read",
+        }
+    }
+}
@@ -0,0 +1,199 @@
+//! Support for `ParseMode::Strict`.
+//!
+//! Required fields of the major types default through `required` functions
+//! in `types.rs`, which call `defaulted`. While `from_value` runs, the first
+//! such field is recorded together with its JSON path and the parse fails;
+//! in lenient parsing the call does nothing.
+
+use serde::de::{self, DeserializeOwned, DeserializeSeed, IntoDeserializer, Visitor};
+use serde_json::Value;
+use std::cell::RefCell;
+
+#[derive(Default)]
+struct State {
+    path: Vec<Segment>,
+    missing: Option<String>,
+}
+
+enum Segment {
+    Key(String),
+    Index(usize),
+}
+
+thread_local! {
+    static STATE: RefCell<Option<State>> = const { RefCell::new(None) };
+}
+
+/// Parses `value` as `T`, failing if a required field had to be defaulted.
+pub(crate) fn from_value<T: DeserializeOwned>(value: &Value) -> serde_json::Result<T> {
+    STATE.with(|state| *state.borrow_mut() = Some(State::default()));
+    let result = T::deserialize(Strict(value));
+    let missing = STATE.with(|state| state.borrow_mut().take().and_then(|state| state.missing));
+    let data = result?;
+    match missing {
+        Some(field) => Err(de::Error::custom(format!("missing field `{}`", field))),
+        None => Ok(data),
+    }
+}
+
+/// Records that `field` of the object being parsed was left out.
+pub(crate) fn defaulted(field: &str) {
+    STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        let Some(state) = state.as_mut() else {
+            return;
+        };
+        if state.missing.is_some() {
+            return;
+        }
+        let mut path = String::new();
+        for segment in &state.path {
+            match segment {
+                Segment::Key(key) => {
+                    path.push_str(key);
+                    path.push('.');
+                }
+                Segment::Index(index) => {
+                    path.pop();
+                    path.push_str(&format!("[{}].", index));
+                }
+            }
+        }
+        path.push_str(field);
+        state.missing = Some(path);
+    });
+}
+
+fn within<R>(segment: Segment, f: impl FnOnce() -> R) -> R {
+    STATE.with(|state| {
+        if let Some(state) = state.borrow_mut().as_mut() {
+            state.path.push(segment);
+        }
+    });
+    let result = f();
+    STATE.with(|state| {
+        if let Some(state) = state.borrow_mut().as_mut() {
+            state.path.pop();
+        }
+    });
+    result
+}
+
+/// Deserializes a JSON value while keeping track of the path to it.
+struct Strict<'a>(&'a Value);
+
+impl<'de> de::Deserializer<'de> for Strict<'_> {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> serde_json::Result<V::Value> {
+        match self.0 {
+            Value::Null => visitor.visit_unit(),
+            Value::Bool(value) => visitor.visit_bool(*value),
+            Value::Number(number) => {
+                if let Some(value) = number.as_u64() {
+                    visitor.visit_u64(value)
+                } else if let Some(value) = number.as_i64() {
+                    visitor.visit_i64(value)
+                } else {
+                    visitor.visit_f64(number.as_f64().unwrap_or_default())
+                }
+            }
+            Value::String(value) => visitor.visit_str(value),
+            Value::Array(items) => visitor.visit_seq(Seq {
+                items: items.iter().enumerate(),
+            }),
+            Value::Object(fields) => visitor.visit_map(Fields {
+                fields: fields.iter(),
+                value: None,
+            }),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> serde_json::Result<V::Value> {
+        match self.0 {
+            Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> serde_json::Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> serde_json::Result<V::Value> {
+        self.0.clone().deserialize_enum(name, variants, visitor)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+struct Seq<'a> {
+    items: std::iter::Enumerate<std::slice::Iter<'a, Value>>,
+}
+
+impl<'de> de::SeqAccess<'de> for Seq<'_> {
+    type Error = serde_json::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> serde_json::Result<Option<T::Value>> {
+        match self.items.next() {
+            Some((index, value)) => {
+                within(Segment::Index(index), || seed.deserialize(Strict(value))).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.items.len())
+    }
+}
+
+struct Fields<'a> {
+    fields: serde_json::map::Iter<'a>,
+    value: Option<(&'a String, &'a Value)>,
+}
+
+impl<'de> de::MapAccess<'de> for Fields<'_> {
+    type Error = serde_json::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> serde_json::Result<Option<K::Value>> {
+        match self.fields.next() {
+            Some((key, value)) => {
+                self.value = Some((key, value));
+                seed.deserialize(key.as_str().into_deserializer()).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> serde_json::Result<V::Value> {
+        let (key, value) = self
+            .value
+            .take()
+            .ok_or_else(|| <serde_json::Error as de::Error>::custom("value is missing"))?;
+        within(Segment::Key(key.clone()), || seed.deserialize(Strict(value)))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.fields.len())
+    }
+}
@@ -1,156 +1,1766 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+/// A point in time as returned by Discourse, kept as the ISO 8601 string
+/// the server sent so that it serializes back out unchanged.
+///
+/// The `chrono` feature only adds to this type: the string is also parsed
+/// once into a `DateTime<Utc>`, available through `datetime()` for sorting
+/// and comparing, and values can be built from a `DateTime<Utc>`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Timestamp {
+    raw: String,
+    #[cfg(feature = "chrono")]
+    datetime: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl Timestamp {
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+
+    /// The parsed time, or `None` if the server sent something that isn't
+    /// an RFC 3339 timestamp.
+    #[cfg(feature = "chrono")]
+    pub fn datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.datetime
+    }
+}
+
+impl From<String> for Timestamp {
+    fn from(raw: String) -> Self {
+        Timestamp {
+            #[cfg(feature = "chrono")]
+            datetime: chrono::DateTime::parse_from_rfc3339(&raw)
+                .ok()
+                .map(|datetime| datetime.with_timezone(&chrono::Utc)),
+            raw,
+        }
+    }
+}
+
+impl From<&str> for Timestamp {
+    fn from(raw: &str) -> Self {
+        Timestamp::from(raw.to_string())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::DateTime<chrono::Utc>> for Timestamp {
+    fn from(datetime: chrono::DateTime<chrono::Utc>) -> Self {
+        Timestamp {
+            raw: datetime.to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+            datetime: Some(datetime),
+        }
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.raw)
+    }
+}
+
+impl Serialize for Timestamp {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.raw)
+    }
+}
+
+impl<'de> Deserialize<'de> for Timestamp {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Timestamp::from)
+    }
+}
+
+macro_rules! id_type {
+    ($(#[$meta:meta])* $name:ident($inner:ty)) => {
+        $(#[$meta])*
+        #[derive(
+            Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Default,
+        )]
+        #[serde(transparent)]
+        pub struct $name(pub $inner);
+
+        impl From<$inner> for $name {
+            fn from(id: $inner) -> Self {
+                $name(id)
+            }
+        }
+
+        impl From<$name> for $inner {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+    };
+}
+
+id_type!(TopicId(u64));
+id_type!(PostId(u64));
+id_type!(CategoryId(u64));
+id_type!(ChannelId(u64));
+id_type!(MessageId(u64));
+id_type!(
+    /// User ids are signed: Discourse uses negative ids for the system user
+    /// and other built-in accounts.
+    UserId(i64)
+);
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ErrorResponse {
+    #[serde(default)]
     pub errors: Vec<String>,
     #[serde(default)]
-    pub error_type: Option<String>,
+    pub error_type: Option<String>,
+    #[serde(default)]
+    pub extras: Option<ErrorExtras>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ErrorExtras {
+    #[serde(default)]
+    pub wait_seconds: Option<f64>,
+    #[serde(default)]
+    pub time_left: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct Topic {
+    #[serde(default = "required::id")]
+    pub id: TopicId,
+    #[serde(default = "required::title")]
+    pub title: String,
+    #[serde(default = "required::slug")]
+    pub slug: String,
+    #[serde(default = "required::posts_count")]
+    pub posts_count: u32,
+    #[serde(default = "required::reply_count")]
+    pub reply_count: u32,
+    #[serde(default = "required::views")]
+    pub views: u32,
+    #[serde(default = "required::like_count")]
+    pub like_count: u32,
+    #[serde(default = "required::created_at")]
+    pub created_at: Timestamp,
+    pub last_posted_at: Option<Timestamp>,
+    #[serde(default = "required::pinned")]
+    pub pinned: bool,
+    #[serde(default = "default_true")]
+    pub visible: bool,
+    #[serde(default = "required::closed")]
+    pub closed: bool,
+    #[serde(default = "required::archived")]
+    pub archived: bool,
+    #[serde(default = "required::has_summary")]
+    pub has_summary: bool,
+    pub category_id: Option<CategoryId>,
+    #[serde(default)]
+    pub posters: Vec<Poster>,
+    #[serde(default, deserialize_with = "deserialize_tag_names")]
+    pub tags: Vec<String>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Accepts topic tags both as plain names (`["howto"]`) and as the objects
+/// newer Discourse versions return (`[{"id": 1, "name": "howto", ...}]`).
+fn deserialize_tag_names<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum TagName {
+        Name(String),
+        Object { name: String },
+    }
+
+    let tags = Option::<Vec<TagName>>::deserialize(deserializer)?.unwrap_or_default();
+    Ok(tags
+        .into_iter()
+        .map(|tag| match tag {
+            TagName::Name(name) | TagName::Object { name } => name,
+        })
+        .collect())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Poster {
+    pub user_id: UserId,
+    pub description: String,
+    #[serde(default)]
+    pub extras: Option<String>,
+    #[serde(default)]
+    pub primary_group_id: Option<u64>,
+    #[serde(default)]
+    pub flair_group_id: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LatestResponse {
+    pub topic_list: TopicList,
+    pub users: Vec<User>,
+    #[serde(default)]
+    pub primary_groups: Vec<Group>,
+    #[serde(default)]
+    pub flair_groups: Vec<Group>,
+}
+
+impl LatestResponse {
+    /// Resolves a poster's `primary_group_id` or `flair_group_id` to the
+    /// group name using the groups sideloaded with the topic list.
+    pub fn group_name(&self, group_id: u64) -> Option<&str> {
+        self.primary_groups
+            .iter()
+            .chain(&self.flair_groups)
+            .find(|group| group.id == group_id)
+            .map(|group| group.name.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopicList {
+    pub topics: Vec<Topic>,
+    #[serde(default)]
+    pub more_topics_url: Option<String>,
+    #[serde(default)]
+    pub per_page: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct TopicResponse {
+    #[serde(default)]
+    pub post_stream: PostStream,
+    #[serde(default = "required::id")]
+    pub id: TopicId,
+    pub title: Option<String>,
+    pub posts_count: Option<u32>,
+    pub category_id: Option<CategoryId>,
+    #[serde(default)]
+    pub closed: bool,
+    #[serde(default)]
+    pub archived: bool,
+    #[serde(default)]
+    pub pinned: bool,
+    #[serde(default)]
+    pub pinned_globally: bool,
+    pub pinned_until: Option<Timestamp>,
+    #[serde(default = "default_true")]
+    pub visible: bool,
+    #[serde(default, deserialize_with = "deserialize_tag_names")]
+    pub tags: Vec<String>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Defaults for the fields of major types that Discourse normally always
+/// sends. Lenient parsing falls back to them when a field is left out;
+/// `ParseMode::Strict` records the field and rejects the response.
+mod required {
+    macro_rules! required_fields {
+        ($($field:ident),* $(,)?) => {
+            $(
+                pub(super) fn $field<T: Default>() -> T {
+                    crate::strict::defaulted(stringify!($field));
+                    T::default()
+                }
+            )*
+        };
+    }
+
+    required_fields!(
+        archived, avatar_template, chat_channel_id, closed, color, cooked, created_at,
+        has_summary, high_priority, id, like_count, message, name, notification_type, pinned,
+        post_number, post_type, posts_count, quote_count, read, reads, reply_count, score, slug,
+        text, text_color, title, topic_count, topic_id, user, user_id, username, views,
+    );
+}
+
+/// Topic flags that can be toggled through `PUT /t/{id}/status`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TopicStatus {
+    Closed,
+    Archived,
+    Pinned,
+    PinnedGlobally,
+    Visible,
+}
+
+impl TopicStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TopicStatus::Closed => "closed",
+            TopicStatus::Archived => "archived",
+            TopicStatus::Pinned => "pinned",
+            TopicStatus::PinnedGlobally => "pinned_globally",
+            TopicStatus::Visible => "visible",
+        }
+    }
+}
+
+/// The personal message lists Discourse keeps for each user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrivateMessageFolder {
+    Inbox,
+    Sent,
+    Archive,
+    Unread,
+    New,
+}
+
+impl PrivateMessageFolder {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PrivateMessageFolder::Inbox => "private-messages",
+            PrivateMessageFolder::Sent => "private-messages-sent",
+            PrivateMessageFolder::Archive => "private-messages-archive",
+            PrivateMessageFolder::Unread => "private-messages-unread",
+            PrivateMessageFolder::New => "private-messages-new",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BasicTopic {
+    pub id: TopicId,
+    pub title: String,
+    #[serde(default)]
+    pub fancy_title: Option<String>,
+    pub slug: String,
+    #[serde(default)]
+    pub posts_count: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BasicTopicResponse {
+    pub basic_topic: BasicTopic,
+}
+
+/// Where `move_posts` should put the selected posts.
+#[derive(Debug, Clone)]
+pub enum PostDestination {
+    NewTopic {
+        title: String,
+        category_id: Option<CategoryId>,
+        tags: Vec<String>,
+    },
+    ExistingTopic {
+        topic_id: TopicId,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MovePostsResponse {
+    pub success: String,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct PostStream {
+    pub posts: Vec<Post>,
+    #[serde(default)]
+    pub stream: Vec<PostId>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct Post {
+    #[serde(default = "required::id")]
+    pub id: PostId,
+    #[serde(default = "required::username")]
+    pub username: String,
+    pub name: Option<String>,
+    pub user_id: Option<UserId>,
+    pub avatar_template: Option<String>,
+    pub trust_level: Option<u32>,
+    #[serde(default = "required::created_at")]
+    pub created_at: Timestamp,
+    pub updated_at: Option<Timestamp>,
+    #[serde(default = "required::cooked")]
+    pub cooked: String,
+    pub raw: Option<String>,
+    #[serde(default = "required::post_number")]
+    pub post_number: u32,
+    #[serde(default = "required::post_type")]
+    pub post_type: PostType,
+    #[serde(default = "required::reply_count")]
+    pub reply_count: u32,
+    pub reply_to_post_number: Option<u32>,
+    pub reply_to_user: Option<ReplyToUser>,
+    #[serde(default = "required::quote_count")]
+    pub quote_count: u32,
+    #[serde(default = "required::reads")]
+    pub reads: u32,
+    #[serde(default = "required::score")]
+    pub score: f64,
+    #[serde(default = "required::topic_id")]
+    pub topic_id: TopicId,
+    pub version: Option<u32>,
+    #[serde(default)]
+    pub actions_summary: Vec<ActionSummary>,
+    #[serde(default)]
+    pub link_counts: Vec<LinkCount>,
+    #[serde(default)]
+    pub polls: Vec<Poll>,
+    #[serde(default)]
+    pub yours: bool,
+    #[serde(default)]
+    pub can_edit: bool,
+    #[serde(default)]
+    pub can_delete: bool,
+    #[serde(default)]
+    pub wiki: bool,
+    #[serde(default)]
+    pub hidden: bool,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Post {
+    /// How many times the given action (e.g. a like) was taken on the post.
+    pub fn action_count(&self, action: PostActionType) -> u32 {
+        self.actions_summary
+            .iter()
+            .find(|summary| summary.id == action)
+            .and_then(|summary| summary.count)
+            .unwrap_or(0)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReplyToUser {
+    pub username: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub avatar_template: Option<String>,
+}
+
+/// Per-action counts and the current user's permissions for a post.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionSummary {
+    pub id: PostActionType,
+    #[serde(default)]
+    pub count: Option<u32>,
+    #[serde(default)]
+    pub acted: bool,
+    #[serde(default)]
+    pub can_act: bool,
+    #[serde(default)]
+    pub can_undo: bool,
+    #[serde(default)]
+    pub hidden: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinkCount {
+    pub url: String,
+    #[serde(default)]
+    pub internal: bool,
+    #[serde(default)]
+    pub reflection: bool,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub clicks: u32,
+}
+
+/// A poll from the bundled poll plugin.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Poll {
+    pub name: String,
+    #[serde(rename = "type", default)]
+    pub poll_type: Option<String>,
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub public: Option<bool>,
+    #[serde(default)]
+    pub results: Option<String>,
+    #[serde(default)]
+    pub close: Option<String>,
+    #[serde(default)]
+    pub voters: Option<u32>,
+    #[serde(default)]
+    pub options: Vec<PollOption>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PollOption {
+    pub id: String,
+    pub html: String,
+    #[serde(default)]
+    pub votes: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(from = "u32", into = "u32")]
+pub enum PostType {
+    #[default]
+    Regular,
+    ModeratorAction,
+    SmallAction,
+    Whisper,
+    Other(u32),
+}
+
+impl From<u32> for PostType {
+    fn from(id: u32) -> Self {
+        match id {
+            1 => PostType::Regular,
+            2 => PostType::ModeratorAction,
+            3 => PostType::SmallAction,
+            4 => PostType::Whisper,
+            other => PostType::Other(other),
+        }
+    }
+}
+
+impl From<PostType> for u32 {
+    fn from(post_type: PostType) -> Self {
+        match post_type {
+            PostType::Regular => 1,
+            PostType::ModeratorAction => 2,
+            PostType::SmallAction => 3,
+            PostType::Whisper => 4,
+            PostType::Other(id) => id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PostResponse {
+    pub post: Post,
+}
+
+/// One entry in a post's edit history, with diffs against the previous
+/// revision.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PostRevision {
+    pub post_id: PostId,
+    pub created_at: Timestamp,
+    pub current_revision: u32,
+    #[serde(default)]
+    pub previous_revision: Option<u32>,
+    #[serde(default)]
+    pub next_revision: Option<u32>,
+    #[serde(default)]
+    pub first_revision: Option<u32>,
+    #[serde(default)]
+    pub last_revision: Option<u32>,
+    #[serde(default)]
+    pub current_version: Option<u32>,
+    #[serde(default)]
+    pub version_count: Option<u32>,
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub display_username: Option<String>,
+    #[serde(default)]
+    pub edit_reason: Option<String>,
+    #[serde(default)]
+    pub previous_hidden: bool,
+    #[serde(default)]
+    pub current_hidden: bool,
+    #[serde(default)]
+    pub body_changes: Option<BodyChanges>,
+    #[serde(default)]
+    pub title_changes: Option<TitleChanges>,
+    #[serde(default)]
+    pub category_id_changes: Option<RevisionChange<Option<CategoryId>>>,
+    #[serde(default)]
+    pub tags_changes: Option<RevisionChange<Vec<String>>>,
+    #[serde(default)]
+    pub wiki: Option<bool>,
+    #[serde(default)]
+    pub can_edit: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BodyChanges {
+    pub inline: String,
+    #[serde(default)]
+    pub side_by_side: Option<String>,
+    #[serde(default)]
+    pub side_by_side_markdown: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TitleChanges {
+    pub inline: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevisionChange<T> {
+    pub previous: T,
+    pub current: T,
+}
+
+/// Actions a user can take on a post: likes, flags and any custom action
+/// types installed by plugins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "u32", into = "u32")]
+pub enum PostActionType {
+    Like,
+    OffTopic,
+    Inappropriate,
+    NotifyUser,
+    NotifyModerators,
+    Spam,
+    Illegal,
+    Custom(u32),
+}
+
+impl PostActionType {
+    pub fn id(&self) -> u32 {
+        (*self).into()
+    }
+
+    pub fn is_flag(&self) -> bool {
+        !matches!(self, PostActionType::Like | PostActionType::Custom(_))
+    }
+}
+
+impl From<u32> for PostActionType {
+    fn from(id: u32) -> Self {
+        match id {
+            2 => PostActionType::Like,
+            3 => PostActionType::OffTopic,
+            4 => PostActionType::Inappropriate,
+            6 => PostActionType::NotifyUser,
+            7 => PostActionType::NotifyModerators,
+            8 => PostActionType::Spam,
+            10 => PostActionType::Illegal,
+            other => PostActionType::Custom(other),
+        }
+    }
+}
+
+impl From<PostActionType> for u32 {
+    fn from(action: PostActionType) -> Self {
+        match action {
+            PostActionType::Like => 2,
+            PostActionType::OffTopic => 3,
+            PostActionType::Inappropriate => 4,
+            PostActionType::NotifyUser => 6,
+            PostActionType::NotifyModerators => 7,
+            PostActionType::Spam => 8,
+            PostActionType::Illegal => 10,
+            PostActionType::Custom(id) => id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PostActionUser {
+    pub id: UserId,
+    pub username: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    pub avatar_template: String,
+    #[serde(default)]
+    pub post_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PostActionUsersResponse {
+    pub post_action_users: Vec<PostActionUser>,
+    #[serde(default)]
+    pub total_rows_post_action_users: Option<u32>,
+}
+
+/// The record a bookmark points at. It (de)serializes as the
+/// `bookmarkable_type` and `bookmarkable_id` pair Discourse uses; types
+/// registered by plugins end up in `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "RawBookmarkable", into = "RawBookmarkable")]
+pub enum BookmarkableType {
+    Post(PostId),
+    Topic(TopicId),
+    ChatMessage(MessageId),
+    Other { kind: String, id: u64 },
+}
+
+impl BookmarkableType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            BookmarkableType::Post(_) => "Post",
+            BookmarkableType::Topic(_) => "Topic",
+            BookmarkableType::ChatMessage(_) => "Chat::Message",
+            BookmarkableType::Other { kind, .. } => kind,
+        }
+    }
+
+    pub fn id(&self) -> u64 {
+        match self {
+            BookmarkableType::Post(id) => id.0,
+            BookmarkableType::Topic(id) => id.0,
+            BookmarkableType::ChatMessage(id) => id.0,
+            BookmarkableType::Other { id, .. } => *id,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct RawBookmarkable {
+    bookmarkable_type: String,
+    bookmarkable_id: u64,
+}
+
+impl From<RawBookmarkable> for BookmarkableType {
+    fn from(raw: RawBookmarkable) -> Self {
+        let id = raw.bookmarkable_id;
+        match raw.bookmarkable_type.as_str() {
+            "Post" => BookmarkableType::Post(PostId(id)),
+            "Topic" => BookmarkableType::Topic(TopicId(id)),
+            "Chat::Message" => BookmarkableType::ChatMessage(MessageId(id)),
+            _ => BookmarkableType::Other {
+                kind: raw.bookmarkable_type,
+                id,
+            },
+        }
+    }
+}
+
+impl From<BookmarkableType> for RawBookmarkable {
+    fn from(bookmarkable: BookmarkableType) -> Self {
+        RawBookmarkable {
+            bookmarkable_id: bookmarkable.id(),
+            bookmarkable_type: bookmarkable.as_str().to_string(),
+        }
+    }
+}
+
+/// When Discourse should delete a bookmark on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "u32", into = "u32")]
+pub enum BookmarkAutoDeletePreference {
+    Never,
+    WhenReminderSent,
+    OnOwnerReply,
+    ClearReminder,
+    Other(u32),
+}
+
+impl From<u32> for BookmarkAutoDeletePreference {
+    fn from(id: u32) -> Self {
+        match id {
+            0 => BookmarkAutoDeletePreference::Never,
+            1 => BookmarkAutoDeletePreference::WhenReminderSent,
+            2 => BookmarkAutoDeletePreference::OnOwnerReply,
+            3 => BookmarkAutoDeletePreference::ClearReminder,
+            other => BookmarkAutoDeletePreference::Other(other),
+        }
+    }
+}
+
+impl From<BookmarkAutoDeletePreference> for u32 {
+    fn from(preference: BookmarkAutoDeletePreference) -> Self {
+        match preference {
+            BookmarkAutoDeletePreference::Never => 0,
+            BookmarkAutoDeletePreference::WhenReminderSent => 1,
+            BookmarkAutoDeletePreference::OnOwnerReply => 2,
+            BookmarkAutoDeletePreference::ClearReminder => 3,
+            BookmarkAutoDeletePreference::Other(id) => id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Bookmark {
+    pub id: u64,
+    #[serde(flatten)]
+    pub bookmarkable: BookmarkableType,
+    #[serde(default)]
+    pub bookmarkable_url: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub reminder_at: Option<Timestamp>,
+    #[serde(default)]
+    pub auto_delete_preference: Option<BookmarkAutoDeletePreference>,
+    #[serde(default)]
+    pub pinned: bool,
+    pub created_at: Timestamp,
+    #[serde(default)]
+    pub updated_at: Option<Timestamp>,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub fancy_title: Option<String>,
+    #[serde(default)]
+    pub excerpt: Option<String>,
+    #[serde(default)]
+    pub topic_id: Option<TopicId>,
+    #[serde(default)]
+    pub linked_post_number: Option<u32>,
+    #[serde(default)]
+    pub slug: Option<String>,
+    #[serde(default)]
+    pub category_id: Option<CategoryId>,
+    #[serde(default, deserialize_with = "deserialize_tag_names")]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub deleted: bool,
+    #[serde(default)]
+    pub hidden: bool,
+    #[serde(default)]
+    pub closed: bool,
+    #[serde(default)]
+    pub archived: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct BookmarksResponse {
+    #[serde(default)]
+    pub user_bookmark_list: UserBookmarkList,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct UserBookmarkList {
+    #[serde(default)]
+    pub bookmarks: Vec<Bookmark>,
+    #[serde(default)]
+    pub more_bookmarks_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateBookmarkResponse {
+    pub success: String,
+    pub id: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct User {
+    #[serde(default = "required::id")]
+    pub id: UserId,
+    #[serde(default = "required::username")]
+    pub username: String,
+    pub name: Option<String>,
+    #[serde(default = "required::avatar_template")]
+    pub avatar_template: String,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct UserDetail {
+    #[serde(default = "required::id")]
+    pub id: UserId,
+    #[serde(default = "required::username")]
+    pub username: String,
+    pub name: Option<String>,
+    #[serde(default = "required::avatar_template")]
+    pub avatar_template: String,
+    pub title: Option<String>,
+    #[serde(default)]
+    pub trust_level: u32,
+    #[serde(default)]
+    pub badge_count: u32,
+    #[serde(default)]
+    pub post_count: u32,
+    #[serde(default)]
+    pub time_read: u64,
+    #[serde(default)]
+    pub profile_view_count: u32,
+    pub created_at: Option<Timestamp>,
+    pub last_seen_at: Option<Timestamp>,
+    pub last_posted_at: Option<Timestamp>,
+    #[serde(default)]
+    pub admin: bool,
+    #[serde(default)]
+    pub moderator: bool,
+    #[serde(default)]
+    pub staged: bool,
+    pub primary_group_id: Option<u64>,
+    pub flair_group_id: Option<u64>,
+    pub bio_raw: Option<String>,
+    pub bio_cooked: Option<String>,
+    pub website: Option<String>,
+    pub location: Option<String>,
+    #[serde(default)]
+    pub groups: Vec<Group>,
+    pub user_fields: Option<serde_json::Map<String, serde_json::Value>>,
+    #[serde(default)]
+    pub custom_fields: serde_json::Map<String, serde_json::Value>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserResponse {
+    pub user: UserDetail,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NewUser {
+    pub name: String,
+    pub email: String,
+    pub password: String,
+    pub username: String,
+    pub active: bool,
+    pub approved: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_fields: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateUserResponse {
+    pub success: bool,
+    #[serde(default)]
+    pub active: bool,
+    #[serde(default)]
+    pub message: String,
+    #[serde(default)]
+    pub user_id: Option<UserId>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActingUser {
+    pub id: UserId,
+    pub username: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub avatar_template: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Suspension {
+    #[serde(default)]
+    pub suspend_reason: Option<String>,
+    #[serde(default)]
+    pub full_suspend_reason: Option<String>,
+    #[serde(default)]
+    pub suspended_till: Option<Timestamp>,
+    #[serde(default)]
+    pub suspended_at: Option<Timestamp>,
+    #[serde(default)]
+    pub suspended_by: Option<ActingUser>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuspensionResponse {
+    pub suspension: Suspension,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Silence {
+    #[serde(default)]
+    pub silenced: bool,
+    #[serde(default)]
+    pub silence_reason: Option<String>,
+    #[serde(default)]
+    pub silenced_till: Option<Timestamp>,
+    #[serde(default)]
+    pub silenced_at: Option<Timestamp>,
+    #[serde(default)]
+    pub silenced_by: Option<ActingUser>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SilenceResponse {
+    pub silence: Silence,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnonymizeUserResponse {
+    pub success: String,
+    pub username: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeleteUserResponse {
+    pub deleted: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct Group {
+    #[serde(default = "required::id")]
+    pub id: u64,
+    #[serde(default = "required::name")]
+    pub name: String,
+    pub display_name: Option<String>,
+    pub full_name: Option<String>,
+    #[serde(default)]
+    pub automatic: bool,
+    #[serde(default)]
+    pub user_count: u32,
+    pub title: Option<String>,
+    pub flair_url: Option<String>,
+    pub flair_bg_color: Option<String>,
+    pub flair_color: Option<String>,
+    pub bio_raw: Option<String>,
+    pub bio_cooked: Option<String>,
+    pub visibility_level: Option<u32>,
+    pub mentionable_level: Option<u32>,
+    pub messageable_level: Option<u32>,
+    pub primary_group: Option<bool>,
+    pub grant_trust_level: Option<u32>,
+    pub public_admission: Option<bool>,
+    pub public_exit: Option<bool>,
+    pub allow_membership_requests: Option<bool>,
+    pub has_messages: Option<bool>,
+    pub is_group_user: Option<bool>,
+    pub is_group_owner: Option<bool>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupsResponse {
+    pub groups: Vec<Group>,
+    #[serde(default)]
+    pub total_rows_groups: u32,
+    #[serde(default)]
+    pub load_more_groups: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupResponse {
+    pub group: Group,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BasicGroupResponse {
+    pub basic_group: Group,
+}
+
+/// Settings sent when creating or updating a group. Fields left as `None`
+/// keep the Discourse default or current value.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GroupSettings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub full_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bio_raw: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility_level: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mentionable_level: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub messageable_level: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub primary_group: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grant_trust_level: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public_admission: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public_exit: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allow_membership_requests: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flair_icon: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flair_bg_color: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flair_color: Option<String>,
+    /// Comma separated usernames, only honoured on creation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usernames: Option<String>,
+    /// Comma separated usernames, only honoured on creation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner_usernames: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupMember {
+    pub id: UserId,
+    pub username: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    pub avatar_template: String,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub last_posted_at: Option<Timestamp>,
+    #[serde(default)]
+    pub last_seen_at: Option<Timestamp>,
+    #[serde(default)]
+    pub added_at: Option<Timestamp>,
+    #[serde(default)]
+    pub timezone: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupMembersResponse {
+    pub members: Vec<GroupMember>,
+    #[serde(default)]
+    pub owners: Vec<GroupMember>,
+    pub meta: GroupMembersMeta,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupMembersMeta {
+    pub total: u32,
+    pub limit: u32,
+    pub offset: u32,
+}
+
+/// Identifies users to add to or remove from a group.
+#[derive(Debug, Clone)]
+pub enum GroupMembers {
+    Usernames(Vec<String>),
+    Emails(Vec<String>),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AddGroupMembersResponse {
+    pub success: String,
+    #[serde(default)]
+    pub usernames: Vec<String>,
+    #[serde(default)]
+    pub emails: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserSummaryResponse {
+    pub user_summary: UserSummary,
+    #[serde(default)]
+    pub topics: Vec<BasicTopic>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserSummary {
+    #[serde(default)]
+    pub likes_given: u32,
+    #[serde(default)]
+    pub likes_received: u32,
+    #[serde(default)]
+    pub topics_entered: u32,
+    #[serde(default)]
+    pub posts_read_count: u32,
+    #[serde(default)]
+    pub days_visited: u32,
+    #[serde(default)]
+    pub topic_count: u32,
+    #[serde(default)]
+    pub post_count: u32,
+    #[serde(default)]
+    pub time_read: u64,
+    #[serde(default)]
+    pub recent_time_read: u64,
+    #[serde(default)]
+    pub bookmark_count: u32,
+    #[serde(default)]
+    pub solved_count: Option<u32>,
+    #[serde(default)]
+    pub can_see_summary_stats: bool,
+    #[serde(default)]
+    pub topic_ids: Vec<TopicId>,
+    #[serde(default)]
+    pub replies: Vec<UserSummaryReply>,
+    #[serde(default)]
+    pub most_liked_by_users: Vec<UserSummaryUser>,
+    #[serde(default)]
+    pub most_liked_users: Vec<UserSummaryUser>,
+    #[serde(default)]
+    pub most_replied_to_users: Vec<UserSummaryUser>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserSummaryReply {
+    pub topic_id: TopicId,
+    pub post_number: u32,
+    #[serde(default)]
+    pub like_count: u32,
+    #[serde(default)]
+    pub created_at: Option<Timestamp>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserSummaryUser {
+    pub id: UserId,
+    pub username: String,
+    #[serde(default)]
+    pub name: Option<String>,
+    pub avatar_template: String,
+    #[serde(default)]
+    pub count: u32,
+}
+
+/// Kinds of entries in a user's activity stream (`/user_actions.json`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "u32", into = "u32")]
+pub enum UserActionType {
+    Like,
+    WasLiked,
+    NewTopic,
+    Reply,
+    Response,
+    Mention,
+    Quote,
+    Edit,
+    NewPrivateMessage,
+    GotPrivateMessage,
+    Solved,
+    Assigned,
+    Other(u32),
+}
+
+impl From<u32> for UserActionType {
+    fn from(id: u32) -> Self {
+        match id {
+            1 => UserActionType::Like,
+            2 => UserActionType::WasLiked,
+            4 => UserActionType::NewTopic,
+            5 => UserActionType::Reply,
+            6 => UserActionType::Response,
+            7 => UserActionType::Mention,
+            9 => UserActionType::Quote,
+            11 => UserActionType::Edit,
+            12 => UserActionType::NewPrivateMessage,
+            13 => UserActionType::GotPrivateMessage,
+            15 => UserActionType::Solved,
+            16 => UserActionType::Assigned,
+            other => UserActionType::Other(other),
+        }
+    }
+}
+
+impl From<UserActionType> for u32 {
+    fn from(action_type: UserActionType) -> Self {
+        match action_type {
+            UserActionType::Like => 1,
+            UserActionType::WasLiked => 2,
+            UserActionType::NewTopic => 4,
+            UserActionType::Reply => 5,
+            UserActionType::Response => 6,
+            UserActionType::Mention => 7,
+            UserActionType::Quote => 9,
+            UserActionType::Edit => 11,
+            UserActionType::NewPrivateMessage => 12,
+            UserActionType::GotPrivateMessage => 13,
+            UserActionType::Solved => 15,
+            UserActionType::Assigned => 16,
+            UserActionType::Other(id) => id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserAction {
+    pub action_type: UserActionType,
+    pub created_at: Timestamp,
+    #[serde(default)]
+    pub excerpt: Option<String>,
+    #[serde(default)]
+    pub avatar_template: Option<String>,
+    #[serde(default)]
+    pub acting_avatar_template: Option<String>,
+    #[serde(default)]
+    pub slug: Option<String>,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub topic_id: Option<TopicId>,
+    #[serde(default)]
+    pub post_id: Option<PostId>,
+    #[serde(default)]
+    pub post_number: Option<u32>,
+    #[serde(default)]
+    pub post_type: Option<u32>,
+    #[serde(default)]
+    pub category_id: Option<CategoryId>,
+    #[serde(default)]
+    pub user_id: Option<UserId>,
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub target_user_id: Option<UserId>,
+    #[serde(default)]
+    pub target_username: Option<String>,
+    #[serde(default)]
+    pub target_name: Option<String>,
+    #[serde(default)]
+    pub acting_user_id: Option<UserId>,
+    #[serde(default)]
+    pub acting_username: Option<String>,
+    #[serde(default)]
+    pub acting_name: Option<String>,
+    #[serde(default)]
+    pub deleted: bool,
+    #[serde(default)]
+    pub hidden: bool,
+    #[serde(default)]
+    pub closed: bool,
+    #[serde(default)]
+    pub archived: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Topic {
-    pub id: u64,
-    pub title: String,
-    pub slug: String,
-    pub posts_count: u32,
-    pub reply_count: u32,
-    pub views: u32,
-    pub like_count: u32,
-    pub created_at: String,
-    pub last_posted_at: Option<String>,
-    pub pinned: bool,
-    pub visible: bool,
-    pub closed: bool,
-    pub archived: bool,
-    pub has_summary: bool,
-    pub category_id: Option<u64>,
-    pub posters: Vec<Poster>,
+pub struct UserActionsResponse {
+    pub user_actions: Vec<UserAction>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DirectoryPeriod {
+    All,
+    Yearly,
+    Quarterly,
+    Monthly,
+    Weekly,
+    Daily,
+}
+
+impl DirectoryPeriod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DirectoryPeriod::All => "all",
+            DirectoryPeriod::Yearly => "yearly",
+            DirectoryPeriod::Quarterly => "quarterly",
+            DirectoryPeriod::Monthly => "monthly",
+            DirectoryPeriod::Weekly => "weekly",
+            DirectoryPeriod::Daily => "daily",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DirectoryOrder {
+    LikesReceived,
+    LikesGiven,
+    TopicCount,
+    PostCount,
+    TopicsEntered,
+    PostsRead,
+    DaysVisited,
+}
+
+impl DirectoryOrder {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DirectoryOrder::LikesReceived => "likes_received",
+            DirectoryOrder::LikesGiven => "likes_given",
+            DirectoryOrder::TopicCount => "topic_count",
+            DirectoryOrder::PostCount => "post_count",
+            DirectoryOrder::TopicsEntered => "topics_entered",
+            DirectoryOrder::PostsRead => "posts_read",
+            DirectoryOrder::DaysVisited => "days_visited",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Poster {
-    pub user_id: i64,
-    pub description: String,
+pub struct DirectoryItem {
+    pub id: UserId,
     #[serde(default)]
-    pub extras: Option<String>,
+    pub likes_received: u32,
     #[serde(default)]
-    pub primary_group_id: Option<u64>,
+    pub likes_given: u32,
     #[serde(default)]
-    pub flair_group_id: Option<u64>,
+    pub topics_entered: u32,
+    #[serde(default)]
+    pub topic_count: u32,
+    #[serde(default)]
+    pub post_count: u32,
+    #[serde(default)]
+    pub posts_read: u32,
+    #[serde(default)]
+    pub days_visited: u32,
+    pub user: User,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct LatestResponse {
-    pub topic_list: TopicList,
-    pub users: Vec<User>,
+pub struct DirectoryResponse {
+    pub directory_items: Vec<DirectoryItem>,
+    #[serde(default)]
+    pub meta: DirectoryMeta,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct DirectoryMeta {
+    #[serde(default)]
+    pub total_rows_directory_items: u32,
+    #[serde(default)]
+    pub load_more_directory_items: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct Category {
+    #[serde(default = "required::id")]
+    pub id: CategoryId,
+    #[serde(default = "required::name")]
+    pub name: String,
+    #[serde(default = "required::color")]
+    pub color: String,
+    #[serde(default = "required::text_color")]
+    pub text_color: String,
+    #[serde(default = "required::slug")]
+    pub slug: String,
+    #[serde(default = "required::topic_count")]
+    pub topic_count: u32,
+    pub description: Option<String>,
+    pub description_text: Option<String>,
+    pub has_children: Option<bool>,
+    pub parent_category_id: Option<CategoryId>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct TopicList {
-    pub topics: Vec<Topic>,
+pub struct CategoryList {
+    pub category_list: CategoryListData,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct TopicResponse {
-    pub post_stream: PostStream,
+pub struct CategoryListData {
+    pub categories: Vec<Category>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct Tag {
+    #[serde(default = "required::text")]
+    pub text: String,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub count: u32,
+    pub pm_count: Option<u32>,
+    pub pm_only: Option<bool>,
+    pub target_tag: Option<String>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagsResponse {
+    pub tags: Vec<Tag>,
+    #[serde(default)]
+    pub extras: TagsExtras,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct TagsExtras {
+    #[serde(default)]
+    pub tag_groups: Vec<TagGroupTags>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagGroupTags {
+    pub id: u64,
+    pub name: String,
+    pub tags: Vec<Tag>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagGroup {
     pub id: u64,
+    pub name: String,
     #[serde(default)]
-    pub title: Option<String>,
+    pub tag_names: Vec<String>,
     #[serde(default)]
-    pub posts_count: Option<u32>,
-    pub category_id: Option<u64>,
+    pub parent_tag_name: Vec<String>,
+    #[serde(default)]
+    pub one_per_topic: bool,
+    #[serde(default)]
+    pub permissions: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct PostStream {
-    pub posts: Vec<Post>,
+pub struct TagGroupsResponse {
+    pub tag_groups: Vec<TagGroup>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagGroupResponse {
+    pub tag_group: TagGroup,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResult {
+    #[serde(default)]
+    pub posts: Vec<SearchPost>,
+    #[serde(default)]
+    pub topics: Vec<SearchTopic>,
+    #[serde(default)]
+    pub users: Vec<User>,
+    #[serde(default)]
+    pub categories: Vec<Category>,
+    #[serde(default, deserialize_with = "deserialize_tag_names")]
+    pub tags: Vec<String>,
     #[serde(default)]
-    pub stream: Vec<u64>,
+    pub grouped_search_result: Option<GroupedSearchResult>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Post {
-    pub id: u64,
+pub struct SearchPost {
+    pub id: PostId,
+    #[serde(default)]
+    pub name: Option<String>,
     pub username: String,
-    pub created_at: String,
-    pub cooked: String,
+    pub avatar_template: String,
+    pub created_at: Timestamp,
     #[serde(default)]
-    pub raw: Option<String>,
+    pub like_count: u32,
+    #[serde(default)]
+    pub blurb: String,
     pub post_number: u32,
-    pub post_type: u32,
+    pub topic_id: TopicId,
+    #[serde(default)]
+    pub topic_title_headline: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchTopic {
+    pub id: TopicId,
+    pub title: String,
+    #[serde(default)]
+    pub fancy_title: Option<String>,
+    pub slug: String,
+    #[serde(default)]
+    pub posts_count: u32,
+    #[serde(default)]
     pub reply_count: u32,
-    pub quote_count: u32,
-    pub reads: u32,
-    pub score: f64,
-    pub topic_id: u64,
+    #[serde(default)]
+    pub highest_post_number: u32,
+    #[serde(default)]
+    pub created_at: Option<Timestamp>,
+    #[serde(default)]
+    pub last_posted_at: Option<Timestamp>,
+    #[serde(default)]
+    pub pinned: bool,
+    #[serde(default = "default_true")]
+    pub visible: bool,
+    #[serde(default)]
+    pub closed: bool,
+    #[serde(default)]
+    pub archived: bool,
+    #[serde(default)]
+    pub category_id: Option<CategoryId>,
+    #[serde(default, deserialize_with = "deserialize_tag_names")]
+    pub tags: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct User {
-    pub id: i64,
-    pub username: String,
-    pub name: Option<String>,
-    pub avatar_template: String,
+pub struct GroupedSearchResult {
+    #[serde(default)]
+    pub term: String,
+    #[serde(default)]
+    pub more_posts: Option<bool>,
+    #[serde(default)]
+    pub more_users: Option<bool>,
+    #[serde(default)]
+    pub more_categories: Option<bool>,
+    #[serde(default)]
+    pub more_full_page_results: Option<bool>,
+    #[serde(default)]
+    pub search_log_id: Option<u64>,
+    #[serde(default)]
+    pub can_create_topic: bool,
+    #[serde(default)]
+    pub error: Option<String>,
+    #[serde(default)]
+    pub post_ids: Vec<PostId>,
+    #[serde(default)]
+    pub user_ids: Vec<UserId>,
+    #[serde(default)]
+    pub category_ids: Vec<CategoryId>,
+    #[serde(default)]
+    pub tag_ids: Vec<u64>,
+    #[serde(default)]
+    pub group_ids: Vec<u64>,
+}
+
+/// What an upload is for, which decides the size limits and processing
+/// Discourse applies to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UploadType {
+    Composer,
+    Avatar,
+    ProfileBackground,
+    CardBackground,
+    CustomEmoji,
+    Chat,
+}
+
+impl UploadType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UploadType::Composer => "composer",
+            UploadType::Avatar => "avatar",
+            UploadType::ProfileBackground => "profile_background",
+            UploadType::CardBackground => "card_background",
+            UploadType::CustomEmoji => "custom_emoji",
+            UploadType::Chat => "chat-composer",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Category {
+pub struct Upload {
     pub id: u64,
-    pub name: String,
-    pub color: String,
-    pub text_color: String,
-    pub slug: String,
-    pub topic_count: u32,
-    pub description: Option<String>,
-    pub description_text: Option<String>,
-    pub has_children: Option<bool>,
-    pub parent_category_id: Option<u64>,
+    pub url: String,
+    #[serde(default)]
+    pub short_url: Option<String>,
+    #[serde(default)]
+    pub short_path: Option<String>,
+    pub original_filename: String,
+    pub filesize: u64,
+    #[serde(default)]
+    pub width: Option<u32>,
+    #[serde(default)]
+    pub height: Option<u32>,
+    #[serde(default)]
+    pub thumbnail_width: Option<u32>,
+    #[serde(default)]
+    pub thumbnail_height: Option<u32>,
+    #[serde(default)]
+    pub extension: Option<String>,
+    #[serde(default)]
+    pub human_filesize: Option<String>,
+    #[serde(default)]
+    pub dominant_color: Option<String>,
+}
+
+impl Upload {
+    /// Markdown that embeds this upload in a post's `raw`, the same way the
+    /// Discourse composer does: `![name|WxH](upload://...)` for images and
+    /// `[name|attachment](upload://...) (size)` for everything else.
+    pub fn markdown(&self) -> String {
+        let url = self.short_url.as_deref().unwrap_or(&self.url);
+        match (self.width, self.height) {
+            (Some(width), Some(height)) => {
+                let name = self
+                    .original_filename
+                    .rsplit_once('.')
+                    .map(|(stem, _)| stem)
+                    .unwrap_or(&self.original_filename);
+                format!("![{}|{}x{}]({})", markdown_name(name), width, height, url)
+            }
+            _ => {
+                let name = markdown_name(&self.original_filename);
+                let mut markdown = format!("[{}|attachment]({})", name, url);
+                if let Some(size) = &self.human_filesize {
+                    markdown.push_str(&format!(" ({})", size));
+                }
+                markdown
+            }
+        }
+    }
+}
+
+fn markdown_name(name: &str) -> String {
+    name.replace(['[', ']', '|'], "")
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CategoryList {
-    pub category_list: CategoryListData,
+pub struct PresignedPut {
+    pub key: String,
+    pub url: String,
+    #[serde(default)]
+    pub signed_headers: HashMap<String, String>,
+    pub unique_identifier: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct CategoryListData {
-    pub categories: Vec<Category>,
+pub struct CreateMultipartResponse {
+    pub external_upload_identifier: String,
+    pub key: String,
+    pub unique_identifier: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PresignedPartsResponse {
+    pub presigned_urls: HashMap<String, String>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompletedPart {
+    pub part_number: u32,
+    pub etag: String,
+}
+
+/// Everything needed to continue a multipart upload. It is serializable so
+/// callers can persist it and resume after a crash or a failed part.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MultipartUploadState {
+    pub unique_identifier: String,
+    pub external_upload_identifier: String,
+    pub key: String,
+    pub file_name: String,
+    pub file_size: u64,
+    pub part_size: u64,
+    #[serde(default)]
+    pub completed_parts: Vec<CompletedPart>,
+}
+
+impl MultipartUploadState {
+    pub fn total_parts(&self) -> u32 {
+        self.file_size.div_ceil(self.part_size).max(1) as u32
+    }
+
+    pub fn is_part_completed(&self, part_number: u32) -> bool {
+        self.completed_parts.iter().any(|part| part.part_number == part_number)
+    }
+
+    pub fn bytes_uploaded(&self) -> u64 {
+        self.completed_parts
+            .iter()
+            .map(|part| {
+                let (start, end) = self.part_range(part.part_number);
+                end - start
+            })
+            .sum()
+    }
+
+    /// Byte range `[start, end)` of a 1-based part.
+    pub fn part_range(&self, part_number: u32) -> (u64, u64) {
+        let start = (part_number as u64 - 1) * self.part_size;
+        (start, (start + self.part_size).min(self.file_size))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UploadProgress {
+    pub bytes_uploaded: u64,
+    pub total_bytes: u64,
+    pub parts_completed: u32,
+    pub total_parts: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ChatChannel {
-    pub id: u64,
+    #[serde(default = "required::id")]
+    pub id: ChannelId,
+    #[serde(default = "required::title")]
     pub title: String,
-    #[serde(default)]
     pub slug: Option<String>,
-    #[serde(default)]
     pub description: Option<String>,
-    #[serde(default)]
     pub chatable_id: Option<u64>,
-    #[serde(default)]
     pub chatable_type: Option<String>,
-    #[serde(default)]
     pub memberships_count: Option<u32>,
-    #[serde(default)]
     pub status: Option<String>,
-    #[serde(default)]
     pub allow_channel_wide_mentions: Option<bool>,
-    #[serde(default)]
     pub chatable: Option<serde_json::Value>,
-    #[serde(default)]
     pub chatable_url: Option<String>,
-    #[serde(default)]
     pub current_user_membership: Option<serde_json::Value>,
-    #[serde(default)]
     pub icon_upload_url: Option<String>,
-    #[serde(default)]
     pub last_message: Option<serde_json::Value>,
-    #[serde(default)]
     pub meta: Option<serde_json::Value>,
-    #[serde(default)]
     pub threading_enabled: Option<bool>,
-    #[serde(default)]
     pub unicode_title: Option<String>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -169,18 +1779,24 @@ pub struct ChatChannelsResponse {
     pub unread_thread_overview: Option<serde_json::Value>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ChatMessage {
-    pub id: u64,
+    #[serde(default = "required::id")]
+    pub id: MessageId,
+    #[serde(default = "required::message")]
     pub message: String,
+    #[serde(default = "required::cooked")]
     pub cooked: String,
-    pub created_at: String,
+    #[serde(default = "required::created_at")]
+    pub created_at: Timestamp,
+    #[serde(default = "required::user")]
     pub user: User,
-    pub chat_channel_id: u64,
-    #[serde(default)]
-    pub deleted_at: Option<String>,
-    #[serde(default)]
+    #[serde(default = "required::chat_channel_id")]
+    pub chat_channel_id: ChannelId,
+    pub deleted_at: Option<Timestamp>,
     pub excerpt: Option<String>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -199,60 +1815,259 @@ pub struct ChatMessagesMeta {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateMessageResponse {
     pub success: String,
-    pub message_id: u64,
+    pub message_id: MessageId,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreatePostResponse {
-    pub id: u64,
+    pub id: PostId,
     pub name: Option<String>,
     pub username: String,
     pub avatar_template: String,
-    pub created_at: String,
+    pub created_at: Timestamp,
     pub cooked: String,
     pub post_number: u32,
-    pub post_type: u32,
-    pub updated_at: String,
+    pub post_type: PostType,
+    pub updated_at: Timestamp,
     pub reply_count: u32,
     pub reply_to_post_number: Option<u32>,
     pub quote_count: u32,
-    pub topic_id: u64,
+    pub topic_id: TopicId,
     pub topic_slug: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Notification kinds from Discourse core and its bundled plugins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "u32", into = "u32")]
+pub enum NotificationType {
+    Mentioned,
+    Replied,
+    Quoted,
+    Edited,
+    Liked,
+    PrivateMessage,
+    InvitedToPrivateMessage,
+    InviteeAccepted,
+    Posted,
+    MovedPost,
+    Linked,
+    GrantedBadge,
+    InvitedToTopic,
+    Custom,
+    GroupMentioned,
+    GroupMessageSummary,
+    WatchingFirstPost,
+    TopicReminder,
+    LikedConsolidated,
+    PostApproved,
+    MembershipRequestAccepted,
+    MembershipRequestConsolidated,
+    BookmarkReminder,
+    Reaction,
+    ChatMention,
+    ChatMessage,
+    ChatInvitation,
+    ChatGroupMention,
+    ChatQuoted,
+    Assigned,
+    WatchingCategoryOrTag,
+    NewFeatures,
+    AdminProblems,
+    LinkedConsolidated,
+    ChatWatchedThread,
+    Other(u32),
+}
+
+impl Default for NotificationType {
+    fn default() -> Self {
+        NotificationType::Other(0)
+    }
+}
+
+impl From<u32> for NotificationType {
+    fn from(id: u32) -> Self {
+        match id {
+            1 => NotificationType::Mentioned,
+            2 => NotificationType::Replied,
+            3 => NotificationType::Quoted,
+            4 => NotificationType::Edited,
+            5 => NotificationType::Liked,
+            6 => NotificationType::PrivateMessage,
+            7 => NotificationType::InvitedToPrivateMessage,
+            8 => NotificationType::InviteeAccepted,
+            9 => NotificationType::Posted,
+            10 => NotificationType::MovedPost,
+            11 => NotificationType::Linked,
+            12 => NotificationType::GrantedBadge,
+            13 => NotificationType::InvitedToTopic,
+            14 => NotificationType::Custom,
+            15 => NotificationType::GroupMentioned,
+            16 => NotificationType::GroupMessageSummary,
+            17 => NotificationType::WatchingFirstPost,
+            18 => NotificationType::TopicReminder,
+            19 => NotificationType::LikedConsolidated,
+            20 => NotificationType::PostApproved,
+            22 => NotificationType::MembershipRequestAccepted,
+            23 => NotificationType::MembershipRequestConsolidated,
+            24 => NotificationType::BookmarkReminder,
+            25 => NotificationType::Reaction,
+            29 => NotificationType::ChatMention,
+            30 => NotificationType::ChatMessage,
+            31 => NotificationType::ChatInvitation,
+            32 => NotificationType::ChatGroupMention,
+            33 => NotificationType::ChatQuoted,
+            34 => NotificationType::Assigned,
+            36 => NotificationType::WatchingCategoryOrTag,
+            37 => NotificationType::NewFeatures,
+            38 => NotificationType::AdminProblems,
+            39 => NotificationType::LinkedConsolidated,
+            40 => NotificationType::ChatWatchedThread,
+            other => NotificationType::Other(other),
+        }
+    }
+}
+
+impl From<NotificationType> for u32 {
+    fn from(notification_type: NotificationType) -> Self {
+        match notification_type {
+            NotificationType::Mentioned => 1,
+            NotificationType::Replied => 2,
+            NotificationType::Quoted => 3,
+            NotificationType::Edited => 4,
+            NotificationType::Liked => 5,
+            NotificationType::PrivateMessage => 6,
+            NotificationType::InvitedToPrivateMessage => 7,
+            NotificationType::InviteeAccepted => 8,
+            NotificationType::Posted => 9,
+            NotificationType::MovedPost => 10,
+            NotificationType::Linked => 11,
+            NotificationType::GrantedBadge => 12,
+            NotificationType::InvitedToTopic => 13,
+            NotificationType::Custom => 14,
+            NotificationType::GroupMentioned => 15,
+            NotificationType::GroupMessageSummary => 16,
+            NotificationType::WatchingFirstPost => 17,
+            NotificationType::TopicReminder => 18,
+            NotificationType::LikedConsolidated => 19,
+            NotificationType::PostApproved => 20,
+            NotificationType::MembershipRequestAccepted => 22,
+            NotificationType::MembershipRequestConsolidated => 23,
+            NotificationType::BookmarkReminder => 24,
+            NotificationType::Reaction => 25,
+            NotificationType::ChatMention => 29,
+            NotificationType::ChatMessage => 30,
+            NotificationType::ChatInvitation => 31,
+            NotificationType::ChatGroupMention => 32,
+            NotificationType::ChatQuoted => 33,
+            NotificationType::Assigned => 34,
+            NotificationType::WatchingCategoryOrTag => 36,
+            NotificationType::NewFeatures => 37,
+            NotificationType::AdminProblems => 38,
+            NotificationType::LinkedConsolidated => 39,
+            NotificationType::ChatWatchedThread => 40,
+            NotificationType::Other(id) => id,
+        }
+    }
+}
+
+/// Which notifications `get_notifications_page` returns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NotificationFilter {
+    #[default]
+    All,
+    Read,
+    Unread,
+}
+
+impl NotificationFilter {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NotificationFilter::All => "all",
+            NotificationFilter::Read => "read",
+            NotificationFilter::Unread => "unread",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Notification {
+    #[serde(default = "required::id")]
     pub id: u64,
-    pub user_id: u64,
-    pub notification_type: u32,
+    #[serde(default = "required::user_id")]
+    pub user_id: UserId,
+    #[serde(default = "required::notification_type")]
+    pub notification_type: NotificationType,
+    #[serde(default = "required::read")]
     pub read: bool,
+    #[serde(default = "required::high_priority")]
     pub high_priority: bool,
-    pub created_at: String,
+    #[serde(default = "required::created_at")]
+    pub created_at: Timestamp,
     pub post_number: Option<u32>,
-    pub topic_id: Option<u64>,
+    pub topic_id: Option<TopicId>,
     pub slug: Option<String>,
     pub fancy_title: Option<String>,
     #[serde(default)]
     pub data: NotificationData,
-    #[serde(default)]
     pub acting_user_avatar_template: Option<String>,
-    #[serde(default)]
     pub acting_user_name: Option<String>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
+/// The type-specific payload of a notification. Which fields are set
+/// depends on `notification_type`: badge fields for `GrantedBadge`, group
+/// fields for `GroupMessageSummary` and membership requests, chat fields
+/// for chat notifications and so on.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct NotificationData {
-    #[serde(default)]
     pub topic_title: Option<String>,
-    #[serde(default)]
+    pub original_post_id: Option<PostId>,
+    pub original_post_type: Option<PostType>,
     pub original_username: Option<String>,
-    #[serde(default)]
+    pub revision_number: Option<u32>,
     pub display_username: Option<String>,
-    #[serde(default)]
     pub display_name: Option<String>,
+    pub username: Option<String>,
+    pub count: Option<u32>,
+    pub badge_id: Option<u64>,
+    pub badge_name: Option<String>,
+    pub badge_slug: Option<String>,
+    pub badge_title: Option<bool>,
+    pub group_id: Option<u64>,
+    pub group_name: Option<String>,
+    pub inbox_count: Option<u32>,
+    pub chat_message_id: Option<MessageId>,
+    pub chat_channel_id: Option<ChannelId>,
+    pub chat_thread_id: Option<u64>,
+    pub chat_channel_title: Option<String>,
+    pub chat_channel_slug: Option<String>,
+    pub mentioned_by_username: Option<String>,
+    pub bookmark_name: Option<String>,
+    pub bookmarkable_url: Option<String>,
+    pub message: Option<String>,
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NotificationsResponse {
     pub notifications: Vec<Notification>,
+    #[serde(default)]
+    pub total_rows_notifications: Option<u32>,
+    #[serde(default)]
+    pub seen_notification_id: Option<u64>,
+    #[serde(default)]
+    pub load_more_notifications: Option<String>,
+}
+
+impl NotificationsResponse {
+    pub fn of_type(
+        &self,
+        notification_type: NotificationType,
+    ) -> impl Iterator<Item = &Notification> + '_ {
+        self.notifications
+            .iter()
+            .filter(move |notification| notification.notification_type == notification_type)
+    }
 }
@@ -1,5 +1,5 @@
 use wasm_bindgen::prelude::*;
-use crate::DiscourseClient;
+use crate::{CategoryId, DiscourseClient, PostId, TopicId};
 
 #[wasm_bindgen]
 pub struct WasmDiscourseClient {
@@ -44,7 +44,7 @@ impl WasmDiscourseClient {
         web_sys::console::log_1(&format!("WASM: getTopic called for topic_id {}", topic_id).into());
 
         let result = self.inner
-            .get_topic(topic_id)
+            .get_topic(TopicId(topic_id))
             .await;
 
         #[cfg(target_arch = "wasm32")]
@@ -66,7 +66,7 @@ impl WasmDiscourseClient {
         category_id: Option<u64>,
     ) -> Result<JsValue, JsValue> {
         self.inner
-            .create_topic(&title, &raw, category_id)
+            .create_topic(&title, &raw, category_id.map(CategoryId))
             .await
             .map(|r| serde_wasm_bindgen::to_value(&r).unwrap())
             .map_err(|e| JsValue::from_str(&format!("{:?}", e)))
@@ -80,7 +80,7 @@ impl WasmDiscourseClient {
         reply_to_post_number: Option<u32>,
     ) -> Result<JsValue, JsValue> {
         self.inner
-            .create_post(topic_id, &raw, reply_to_post_number)
+            .create_post(TopicId(topic_id), &raw, reply_to_post_number)
             .await
             .map(|r| serde_wasm_bindgen::to_value(&r).unwrap())
             .map_err(|e| JsValue::from_str(&format!("{:?}", e)))
@@ -89,15 +89,16 @@ impl WasmDiscourseClient {
     #[wasm_bindgen(js_name = updatePost)]
     pub async fn update_post(&self, post_id: u64, raw: String) -> Result<(), JsValue> {
         self.inner
-            .update_post(post_id, &raw)
+            .update_post(PostId(post_id), &raw, None)
             .await
+            .map(|_| ())
             .map_err(|e| JsValue::from_str(&format!("{:?}", e)))
     }
 
     #[wasm_bindgen(js_name = deletePost)]
     pub async fn delete_post(&self, post_id: u64) -> Result<(), JsValue> {
         self.inner
-            .delete_post(post_id)
+            .delete_post(PostId(post_id))
             .await
             .map_err(|e| JsValue::from_str(&format!("{:?}", e)))
     }
@@ -105,7 +106,7 @@ impl WasmDiscourseClient {
     #[wasm_bindgen(js_name = likePost)]
     pub async fn like_post(&self, post_id: u64) -> Result<(), JsValue> {
         self.inner
-            .like_post(post_id)
+            .like_post(PostId(post_id))
             .await
             .map_err(|e| JsValue::from_str(&format!("{:?}", e)))
     }
@@ -113,7 +114,7 @@ impl WasmDiscourseClient {
     #[wasm_bindgen(js_name = unlikePost)]
     pub async fn unlike_post(&self, post_id: u64) -> Result<(), JsValue> {
         self.inner
-            .unlike_post(post_id)
+            .unlike_post(PostId(post_id))
             .await
             .map_err(|e| JsValue::from_str(&format!("{:?}", e)))
     }
@@ -0,0 +1,126 @@
+use discourse_api_rs::{DiscourseClient, Error, NewUser, Timestamp, UserId};
+use serde_json::json;
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn create_user_turns_unsuccessful_reply_into_unprocessable() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/users.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "success": false,
+            "message": "Username must be unique",
+            "errors": { "username": ["must be unique"] },
+            "values": { "name": "Alice", "username": "alice", "email": "alice@example.com" },
+            "is_developer": false
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+    let user = NewUser {
+        name: "Alice".to_string(),
+        email: "alice@example.com".to_string(),
+        password: "correct horse battery staple".to_string(),
+        username: "alice".to_string(),
+        active: true,
+        approved: true,
+        ..Default::default()
+    };
+
+    let error = client.create_user(&user).await.unwrap_err();
+
+    match error {
+        Error::Unprocessable { errors, .. } => {
+            assert_eq!(errors, vec!["Username must be unique"])
+        }
+        other => panic!("expected an unprocessable error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn suspend_user_parses_suspension() {
+    let server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path("/admin/users/3/suspend.json"))
+        .and(body_json(json!({
+            "suspend_until": "2024-06-01T00:00:00Z",
+            "reason": "spam"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "suspension": {
+                "suspend_reason": "spam",
+                "full_suspend_reason": "spam",
+                "suspended_till": "2024-06-01T00:00:00.000Z",
+                "suspended_at": "2024-01-01T00:00:00.000Z",
+                "suspended_by": {
+                    "id": 1,
+                    "username": "admin",
+                    "name": null,
+                    "avatar_template": "/letter_avatar/admin/{size}/1.png"
+                }
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let suspension = client
+        .suspend_user(UserId(3), &Timestamp::from("2024-06-01T00:00:00Z"), "spam")
+        .await
+        .unwrap();
+
+    assert_eq!(suspension.suspend_reason.as_deref(), Some("spam"));
+    assert!(suspension.suspended_till.is_some());
+    assert_eq!(suspension.suspended_by.unwrap().username, "admin");
+}
+
+#[tokio::test]
+async fn silence_user_parses_silence() {
+    let server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path("/admin/users/3/silence.json"))
+        .and(body_json(json!({ "reason": "flooding" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "silence": {
+                "silenced": true,
+                "silence_reason": "flooding",
+                "silenced_till": null,
+                "silenced_at": "2024-01-01T00:00:00.000Z",
+                "silenced_by": { "id": 1, "username": "admin" }
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let silence = client.silence_user(UserId(3), None, "flooding").await.unwrap();
+
+    assert!(silence.silenced);
+    assert!(silence.silenced_till.is_none());
+    assert_eq!(silence.silenced_by.unwrap().id, UserId(1));
+}
+
+#[tokio::test]
+async fn delete_user_sends_cleanup_options() {
+    let server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path("/admin/users/3.json"))
+        .and(body_json(json!({
+            "delete_posts": true,
+            "block_email": false,
+            "block_ip": true
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "deleted": true })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let response = client.delete_user(UserId(3), true, false, true).await.unwrap();
+
+    assert!(response.deleted);
+}
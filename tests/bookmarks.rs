@@ -0,0 +1,80 @@
+use discourse_api_rs::{
+    BookmarkAutoDeletePreference, BookmarkableType, DiscourseClient, Timestamp, TopicId,
+};
+use serde_json::json;
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn create_bookmark_sends_reminder_and_preference() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/bookmarks.json"))
+        .and(body_json(json!({
+            "bookmarkable_type": "Topic",
+            "bookmarkable_id": 8,
+            "reminder_at": "2024-03-01T09:00:00Z",
+            "name": "read later",
+            "auto_delete_preference": 1
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "success": "OK", "id": 31 })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let bookmark = client
+        .create_bookmark(
+            BookmarkableType::Topic(TopicId(8)),
+            Some(&Timestamp::from("2024-03-01T09:00:00Z")),
+            Some("read later"),
+            Some(BookmarkAutoDeletePreference::WhenReminderSent),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(bookmark.id, 31);
+}
+
+#[tokio::test]
+async fn get_user_bookmarks_parses_list_and_empty_response() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/u/alice/bookmarks.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "user_bookmark_list": {
+                "more_bookmarks_url": "/u/alice/bookmarks.json?page=1",
+                "bookmarks": [{
+                    "id": 31,
+                    "bookmarkable_id": 8,
+                    "bookmarkable_type": "Topic",
+                    "name": "read later",
+                    "reminder_at": null,
+                    "auto_delete_preference": 3,
+                    "created_at": "2024-01-01T00:00:00.000Z",
+                    "title": "Backups",
+                    "topic_id": 8,
+                    "tags": ["ops"]
+                }]
+            }
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/u/bob/bookmarks.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "bookmarks": [] })))
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let alice = client.get_user_bookmarks("alice").await.unwrap();
+    let bob = client.get_user_bookmarks("bob").await.unwrap();
+
+    assert_eq!(
+        alice.bookmarks[0].auto_delete_preference,
+        Some(BookmarkAutoDeletePreference::ClearReminder)
+    );
+    assert_eq!(alice.bookmarks[0].bookmarkable, BookmarkableType::Topic(TopicId(8)));
+    assert!(alice.more_bookmarks_url.is_some());
+    assert!(bob.bookmarks.is_empty());
+}
@@ -0,0 +1,37 @@
+use discourse_api_rs::DiscourseClient;
+use reqwest::header::{HeaderName, HeaderValue};
+use serde_json::json;
+use std::time::Duration;
+use wiremock::matchers::{header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn builder_applies_auth_and_transport_options() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/latest.json"))
+        .and(header("Api-Key", "secret"))
+        .and(header("Api-Username", "system"))
+        .and(header("User-Agent", "sync-bot/1.0"))
+        .and(header("X-Trace", "abc"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "users": [],
+            "topic_list": { "topics": [] }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = DiscourseClient::builder(format!("{}//", server.uri()))
+        .api_key("secret", "system")
+        .user_agent("sync-bot/1.0")
+        .timeout(Duration::from_secs(5))
+        .default_header(
+            HeaderName::from_static("x-trace"),
+            HeaderValue::from_static("abc"),
+        )
+        .build()
+        .unwrap();
+
+    client.get_latest().await.unwrap();
+}
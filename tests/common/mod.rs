@@ -0,0 +1,40 @@
+#![allow(dead_code)]
+
+use serde_json::json;
+
+pub fn topic(id: u64) -> serde_json::Value {
+    json!({
+        "id": id,
+        "title": format!("Topic {id}"),
+        "slug": format!("topic-{id}"),
+        "posts_count": 1,
+        "reply_count": 0,
+        "views": 1,
+        "like_count": 0,
+        "created_at": "2024-01-01T00:00:00.000Z",
+        "last_posted_at": null,
+        "pinned": false,
+        "visible": true,
+        "closed": false,
+        "archived": false,
+        "has_summary": false,
+        "category_id": 5,
+        "posters": []
+    })
+}
+
+pub fn post(id: u64, topic_id: u64) -> serde_json::Value {
+    json!({
+        "id": id,
+        "username": "alice",
+        "created_at": "2024-01-01T00:00:00.000Z",
+        "cooked": format!("<p>post {id}</p>"),
+        "post_number": id,
+        "post_type": 1,
+        "reply_count": 0,
+        "quote_count": 0,
+        "reads": 1,
+        "score": 0.0,
+        "topic_id": topic_id
+    })
+}
@@ -0,0 +1,190 @@
+mod common;
+
+use discourse_api_rs::{DiscourseClient, Error, ParseMode, TopicId};
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mount_latest_without_has_summary(server: &MockServer) {
+    let mut topic = common::topic(1);
+    topic.as_object_mut().unwrap().remove("has_summary");
+    topic["bumped_at"] = json!("2024-01-02T00:00:00.000Z");
+    Mock::given(method("GET"))
+        .and(path("/latest.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "users": [],
+            "topic_list": { "topics": [topic] }
+        })))
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn lenient_mode_keeps_unknown_fields_and_defaults_missing_ones() {
+    let server = MockServer::start().await;
+    mount_latest_without_has_summary(&server).await;
+    let client = DiscourseClient::new(server.uri());
+
+    let latest = client.get_latest().await.unwrap();
+    let topic = &latest.topic_list.topics[0];
+
+    assert!(!topic.has_summary);
+    assert_eq!(topic.extra["bumped_at"], "2024-01-02T00:00:00.000Z");
+    let value = serde_json::to_value(topic).unwrap();
+    assert_eq!(value["bumped_at"], "2024-01-02T00:00:00.000Z");
+}
+
+#[tokio::test]
+async fn lenient_mode_treats_topics_without_visible_as_listed() {
+    let mut topic = common::topic(1);
+    topic.as_object_mut().unwrap().remove("visible");
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/latest.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "users": [],
+            "topic_list": { "topics": [topic] }
+        })))
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let latest = client.get_latest().await.unwrap();
+
+    assert!(latest.topic_list.topics[0].visible);
+}
+
+#[tokio::test]
+async fn strict_mode_rejects_missing_fields() {
+    let server = MockServer::start().await;
+    mount_latest_without_has_summary(&server).await;
+    let client = DiscourseClient::builder(server.uri())
+        .parse_mode(ParseMode::Strict)
+        .build()
+        .unwrap();
+
+    let error = client.get_latest().await.unwrap_err();
+
+    match error {
+        Error::Json(error) => assert!(error
+            .to_string()
+            .contains("topic_list.topics[0].has_summary")),
+        other => panic!("expected a JSON error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn strict_mode_accepts_complete_responses() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/latest.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "users": [],
+            "topic_list": { "topics": [common::topic(1)] }
+        })))
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri()).with_parse_mode(ParseMode::Strict);
+
+    let latest = client.get_latest().await.unwrap();
+
+    assert_eq!(latest.topic_list.topics.len(), 1);
+}
+
+#[tokio::test]
+async fn strict_mode_accepts_real_topic_with_action_summaries() {
+    let post = json!({
+        "id": 11,
+        "name": "Alice",
+        "username": "alice",
+        "avatar_template": "/user_avatar/forum.example.com/alice/{size}/3_2.png",
+        "created_at": "2024-01-01T00:00:00.000Z",
+        "cooked": "<p>Backups run nightly.</p>",
+        "post_number": 1,
+        "post_type": 1,
+        "updated_at": "2024-01-01T00:00:00.000Z",
+        "reply_count": 0,
+        "reply_to_post_number": null,
+        "quote_count": 0,
+        "incoming_link_count": 0,
+        "reads": 4,
+        "readers_count": 3,
+        "score": 0.8,
+        "yours": false,
+        "topic_id": 7,
+        "topic_slug": "backups",
+        "display_username": "Alice",
+        "primary_group_name": null,
+        "version": 1,
+        "can_edit": false,
+        "can_delete": false,
+        "can_recover": false,
+        "can_wiki": false,
+        "read": true,
+        "user_title": null,
+        "bookmarked": false,
+        "actions_summary": [
+            { "id": 2, "count": 1, "can_act": true },
+            { "id": 3, "can_act": true },
+            { "id": 8, "can_act": true }
+        ],
+        "moderator": false,
+        "admin": false,
+        "staff": false,
+        "user_id": 3,
+        "hidden": false,
+        "trust_level": 1,
+        "deleted_at": null,
+        "user_deleted": false,
+        "edit_reason": null,
+        "can_view_edit_history": true,
+        "wiki": false
+    });
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/t/7.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "post_stream": { "posts": [post], "stream": [11] },
+            "id": 7,
+            "title": "Backups",
+            "posts_count": 1,
+            "category_id": 5,
+            "closed": false,
+            "archived": false,
+            "pinned": false,
+            "pinned_globally": false,
+            "pinned_until": null,
+            "visible": true,
+            "tags": []
+        })))
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri()).with_parse_mode(ParseMode::Strict);
+
+    let topic = client.get_topic(TopicId(7)).await.unwrap();
+
+    let summary = &topic.post_stream.posts[0].actions_summary[0];
+    assert!(summary.can_act && !summary.acted);
+}
+
+#[tokio::test]
+async fn serialized_types_keep_fields_at_their_default() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/t/7.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "post_stream": { "posts": [common::post(11, 7)], "stream": [11] },
+            "id": 7
+        })))
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let topic = client.get_topic(TopicId(7)).await.unwrap();
+    let value = serde_json::to_value(&topic).unwrap();
+
+    assert_eq!(value["visible"], true);
+    assert_eq!(value["closed"], false);
+    assert_eq!(value["post_stream"]["posts"][0]["can_edit"], false);
+    assert_eq!(value["post_stream"]["posts"][0]["wiki"], false);
+}
@@ -0,0 +1,100 @@
+use discourse_api_rs::{DiscourseClient, Error, PostId, RetryPolicy};
+use reqwest::StatusCode;
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn client_for(status: u16, body: serde_json::Value) -> (MockServer, DiscourseClient) {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/posts/1.json"))
+        .respond_with(ResponseTemplate::new(status).set_body_json(body))
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri()).with_retry_policy(RetryPolicy::disabled());
+    (server, client)
+}
+
+#[tokio::test]
+async fn maps_not_found() {
+    let (_server, client) = client_for(
+        404,
+        json!({
+            "errors": ["The requested URL or resource could not be found."],
+            "error_type": "not_found"
+        }),
+    )
+    .await;
+
+    let error = client.get_post(PostId(1)).await.unwrap_err();
+
+    assert!(error.is_not_found());
+    assert_eq!(error.status(), Some(StatusCode::NOT_FOUND));
+    assert_eq!(error.error_type(), Some("not_found"));
+}
+
+#[tokio::test]
+async fn maps_invalid_access() {
+    let (_server, client) = client_for(
+        403,
+        json!({
+            "errors": ["You are not permitted to view the requested resource."],
+            "error_type": "invalid_access"
+        }),
+    )
+    .await;
+
+    let error = client.get_post(PostId(1)).await.unwrap_err();
+
+    assert!(error.is_invalid_access());
+    assert_eq!(error.status(), Some(StatusCode::FORBIDDEN));
+}
+
+#[tokio::test]
+async fn maps_rate_limited_with_wait_seconds() {
+    let (_server, client) = client_for(
+        429,
+        json!({
+            "errors": ["Slow down."],
+            "error_type": "rate_limit",
+            "extras": { "wait_seconds": 12, "time_left": "12 seconds" }
+        }),
+    )
+    .await;
+
+    let error = client.get_post(PostId(1)).await.unwrap_err();
+
+    assert!(matches!(error, Error::RateLimited { wait_seconds: Some(w), .. } if w == 12.0));
+}
+
+#[tokio::test]
+async fn maps_unprocessable_errors() {
+    let (_server, client) = client_for(
+        422,
+        json!({ "errors": ["Title is too short", "Body is too short"] }),
+    )
+    .await;
+
+    let error = client.get_post(PostId(1)).await.unwrap_err();
+
+    match error {
+        Error::Unprocessable { errors, .. } => assert_eq!(errors.len(), 2),
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn maps_server_errors_without_body() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/posts/1.json"))
+        .respond_with(ResponseTemplate::new(502).set_body_string("Bad Gateway"))
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let error = client.get_post(PostId(1)).await.unwrap_err();
+
+    assert!(matches!(error, Error::Server { response: None, .. }));
+    assert_eq!(error.status(), Some(StatusCode::BAD_GATEWAY));
+}
@@ -0,0 +1,221 @@
+use discourse_api_rs::{DiscourseClient, Error, UploadProgress, UploadType};
+use serde_json::json;
+use wiremock::matchers::{body_json, body_partial_json, method, path, path_regex};
+use wiremock::{Mock, MockServer, Request, Respond, ResponseTemplate};
+
+fn upload_body() -> serde_json::Value {
+    json!({
+        "id": 55,
+        "url": "https://store.example.com/original/big.bin",
+        "short_url": "upload://big.bin",
+        "original_filename": "big.bin",
+        "filesize": 10,
+        "human_filesize": "10 Bytes"
+    })
+}
+
+/// Stands in for `batch-presign-multipart-parts`, pointing every requested
+/// part at the fake object store on the same server.
+struct PresignParts {
+    store_url: String,
+}
+
+impl Respond for PresignParts {
+    fn respond(&self, request: &Request) -> ResponseTemplate {
+        let body: serde_json::Value = serde_json::from_slice(&request.body).unwrap();
+        let urls: serde_json::Map<String, serde_json::Value> = body["part_numbers"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|n| (n.to_string(), json!(format!("{}/store/part/{}", self.store_url, n))))
+            .collect();
+        ResponseTemplate::new(200).set_body_json(json!({ "presigned_urls": urls }))
+    }
+}
+
+/// Stands in for the object store, echoing an ETag derived from the part.
+struct StorePart;
+
+impl Respond for StorePart {
+    fn respond(&self, request: &Request) -> ResponseTemplate {
+        let part = request.url.path().rsplit('/').next().unwrap();
+        ResponseTemplate::new(200).insert_header("ETag", format!("\"etag-{}\"", part))
+    }
+}
+
+async fn mount_discourse(server: &MockServer) {
+    Mock::given(method("POST"))
+        .and(path("/uploads/create-multipart.json"))
+        .and(body_partial_json(json!({ "file_name": "big.bin", "file_size": 10 })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "external_upload_identifier": "s3-upload-id",
+            "key": "temp/big.bin",
+            "unique_identifier": "abc123"
+        })))
+        .mount(server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/uploads/batch-presign-multipart-parts.json"))
+        .respond_with(PresignParts { store_url: server.uri() })
+        .mount(server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/uploads/complete-multipart.json"))
+        .and(body_json(json!({
+            "unique_identifier": "abc123",
+            "parts": [
+                { "part_number": 1, "etag": "\"etag-1\"" },
+                { "part_number": 2, "etag": "\"etag-2\"" },
+                { "part_number": 3, "etag": "\"etag-3\"" }
+            ]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(upload_body()))
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn multipart_upload_reports_progress_and_completes() {
+    let server = MockServer::start().await;
+    mount_discourse(&server).await;
+    Mock::given(method("PUT"))
+        .and(path_regex("^/store/part/[0-9]+$"))
+        .respond_with(StorePart)
+        .expect(3)
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+    let bytes = b"0123456789";
+
+    let mut state = client
+        .start_multipart_upload("big.bin", 10, UploadType::Composer, 4)
+        .await
+        .unwrap();
+    let mut updates: Vec<UploadProgress> = Vec::new();
+    client
+        .upload_multipart_parts(&mut state, bytes, |progress| updates.push(progress))
+        .await
+        .unwrap();
+    let upload = client.complete_multipart_upload(&state).await.unwrap();
+
+    assert_eq!(upload.id, 55);
+    let uploaded: Vec<u64> = updates.iter().map(|p| p.bytes_uploaded).collect();
+    assert_eq!(uploaded, vec![4, 8, 10]);
+    assert_eq!(updates.last().unwrap().total_parts, 3);
+}
+
+#[tokio::test]
+async fn multipart_upload_resumes_after_failed_part() {
+    let server = MockServer::start().await;
+    mount_discourse(&server).await;
+    Mock::given(method("PUT"))
+        .and(path("/store/part/2"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path_regex("^/store/part/[0-9]+$"))
+        .respond_with(StorePart)
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+    let bytes = b"0123456789";
+
+    let mut state = client
+        .start_multipart_upload("big.bin", 10, UploadType::Composer, 4)
+        .await
+        .unwrap();
+    let first_attempt = client.upload_multipart_parts(&mut state, bytes, |_| {}).await;
+    assert!(first_attempt.is_err());
+    assert_eq!(state.completed_parts.len(), 1);
+
+    // The state survives a round trip through storage.
+    let saved = serde_json::to_string(&state).unwrap();
+    let mut state = serde_json::from_str(&saved).unwrap();
+    client
+        .upload_multipart_parts(&mut state, bytes, |_| {})
+        .await
+        .unwrap();
+    client.complete_multipart_upload(&state).await.unwrap();
+
+    let part_one_puts = server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .filter(|request| request.url.path() == "/store/part/1")
+        .count();
+    assert_eq!(part_one_puts, 1);
+}
+
+#[tokio::test]
+async fn multipart_upload_rejects_bytes_of_the_wrong_length() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/uploads/create-multipart.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "external_upload_identifier": "s3-upload-id",
+            "key": "temp/big.bin",
+            "unique_identifier": "abc123"
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/uploads/batch-presign-multipart-parts.json"))
+        .respond_with(PresignParts { store_url: server.uri() })
+        .expect(0)
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let mut state = client
+        .start_multipart_upload("big.bin", 10, UploadType::Composer, 4)
+        .await
+        .unwrap();
+    let error = client
+        .upload_multipart_parts(&mut state, b"01234", |_| {})
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, Error::InvalidInput(_)));
+}
+
+#[tokio::test]
+async fn presigned_put_upload() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/uploads/generate-presigned-put.json"))
+        .and(body_json(json!({ "type": "composer", "file_name": "big.bin", "file_size": 10 })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "key": "temp/big.bin",
+            "url": format!("{}/store/single", server.uri()),
+            "signed_headers": { "x-amz-acl": "private" },
+            "unique_identifier": "def456"
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/store/single"))
+        .and(wiremock::matchers::header("x-amz-acl", "private"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/uploads/complete-external-upload.json"))
+        .and(body_json(json!({ "unique_identifier": "def456" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(upload_body()))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let upload = client
+        .upload_file_presigned(b"0123456789".to_vec(), "big.bin", UploadType::Composer)
+        .await
+        .unwrap();
+
+    assert_eq!(upload.short_url.as_deref(), Some("upload://big.bin"));
+}
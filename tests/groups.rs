@@ -0,0 +1,85 @@
+mod common;
+
+use discourse_api_rs::{DiscourseClient, GroupMembers};
+use serde_json::json;
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn resolves_poster_groups_from_latest() {
+    let mut topic = common::topic(1);
+    topic["posters"] = json!([{
+        "user_id": 3,
+        "description": "Original Poster",
+        "primary_group_id": 41,
+        "flair_group_id": 42
+    }]);
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/latest.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "users": [],
+            "primary_groups": [{ "id": 41, "name": "staff" }],
+            "flair_groups": [{ "id": 42, "name": "donors", "flair_url": "fa-heart" }],
+            "topic_list": { "topics": [topic] }
+        })))
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let latest = client.get_latest().await.unwrap();
+    let poster = &latest.topic_list.topics[0].posters[0];
+
+    assert_eq!(latest.group_name(poster.primary_group_id.unwrap()), Some("staff"));
+    assert_eq!(latest.group_name(poster.flair_group_id.unwrap()), Some("donors"));
+}
+
+#[tokio::test]
+async fn add_group_members_by_email() {
+    let server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path("/groups/41/members.json"))
+        .and(body_json(json!({ "emails": "a@example.com,b@example.com" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "success": "OK",
+            "usernames": ["a", "b"],
+            "emails": ["a@example.com", "b@example.com"]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let added = client
+        .add_group_members(
+            41,
+            &GroupMembers::Emails(vec!["a@example.com".into(), "b@example.com".into()]),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(added.usernames, vec!["a", "b"]);
+}
+
+#[tokio::test]
+async fn add_and_remove_group_owners_send_usernames() {
+    let server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path("/admin/groups/41/owners.json"))
+        .and(body_json(json!({ "group": { "usernames": "alice,bob" } })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "success": "OK" })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/admin/groups/41/owners.json"))
+        .and(body_json(json!({ "group": { "usernames": "bob" } })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "success": "OK" })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    client.add_group_owners(41, &["alice", "bob"]).await.unwrap();
+    client.remove_group_owners(41, &["bob"]).await.unwrap();
+}
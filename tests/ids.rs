@@ -0,0 +1,22 @@
+use discourse_api_rs::{BookmarkableType, PostId, TopicId};
+use serde_json::json;
+
+#[test]
+fn ids_serialize_as_plain_numbers() {
+    assert_eq!(serde_json::to_value(TopicId(7)).unwrap(), json!(7));
+    assert_eq!(serde_json::from_value::<PostId>(json!(12)).unwrap(), PostId(12));
+    assert_eq!(TopicId::from(7).to_string(), "7");
+}
+
+#[test]
+fn bookmarkable_round_trips_type_and_id() {
+    let raw = json!({ "bookmarkable_type": "Post", "bookmarkable_id": 12 });
+
+    let bookmarkable: BookmarkableType = serde_json::from_value(raw.clone()).unwrap();
+
+    assert_eq!(bookmarkable, BookmarkableType::Post(PostId(12)));
+    assert_eq!(serde_json::to_value(&bookmarkable).unwrap(), raw);
+    let other: BookmarkableType =
+        serde_json::from_value(json!({ "bookmarkable_type": "Poll", "bookmarkable_id": 3 })).unwrap();
+    assert_eq!(other.as_str(), "Poll");
+}
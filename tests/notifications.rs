@@ -0,0 +1,136 @@
+use discourse_api_rs::{ChannelId, DiscourseClient, MessageId, NotificationFilter, NotificationType};
+use serde_json::json;
+use wiremock::matchers::{body_json, method, path, query_param, query_param_is_missing};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn notification(id: u64, notification_type: u32, data: serde_json::Value) -> serde_json::Value {
+    json!({
+        "id": id,
+        "user_id": 3,
+        "notification_type": notification_type,
+        "read": false,
+        "high_priority": false,
+        "created_at": "2024-01-01T00:00:00.000Z",
+        "post_number": null,
+        "topic_id": null,
+        "slug": null,
+        "fancy_title": null,
+        "data": data
+    })
+}
+
+#[tokio::test]
+async fn notifications_parse_types_and_payloads() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/notifications.json"))
+        .and(query_param("filter", "unread"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "notifications": [
+                notification(1, 12, json!({
+                    "badge_id": 9,
+                    "badge_name": "Editor",
+                    "badge_slug": "editor",
+                    "badge_title": false,
+                    "username": "alice"
+                })),
+                notification(2, 29, json!({
+                    "chat_message_id": 880,
+                    "chat_channel_id": 4,
+                    "chat_channel_title": "general",
+                    "mentioned_by_username": "bob"
+                })),
+                notification(3, 16, json!({
+                    "group_id": 41,
+                    "group_name": "support",
+                    "inbox_count": 2,
+                    "username": "alice"
+                })),
+                notification(4, 999, json!({}))
+            ],
+            "total_rows_notifications": 4,
+            "seen_notification_id": 3,
+            "load_more_notifications": "/notifications?offset=60&username=alice"
+        })))
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let page = client
+        .get_notifications_page(NotificationFilter::Unread, 0)
+        .await
+        .unwrap();
+
+    let badge = &page.notifications[0];
+    assert_eq!(badge.notification_type, NotificationType::GrantedBadge);
+    assert_eq!(badge.data.badge_name.as_deref(), Some("Editor"));
+    let chat = page.of_type(NotificationType::ChatMention).next().unwrap();
+    assert_eq!(chat.data.chat_message_id, Some(MessageId(880)));
+    assert_eq!(chat.data.chat_channel_id, Some(ChannelId(4)));
+    assert_eq!(page.notifications[2].data.group_name.as_deref(), Some("support"));
+    assert_eq!(page.notifications[3].notification_type, NotificationType::Other(999));
+}
+
+#[tokio::test]
+async fn load_more_notifications_follows_link_until_empty() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/notifications.json"))
+        .and(query_param("offset", "0"))
+        .and(query_param_is_missing("filter"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "notifications": [notification(1, 1, json!({}))],
+            "load_more_notifications": "/notifications?offset=60&username=alice"
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/notifications.json"))
+        .and(query_param("offset", "60"))
+        .and(query_param("username", "alice"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "notifications": [notification(2, 2, json!({}))],
+            "load_more_notifications": "/notifications?offset=120&username=alice"
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/notifications.json"))
+        .and(query_param("offset", "120"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "notifications": [],
+            "load_more_notifications": "/notifications?offset=180&username=alice"
+        })))
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let first = client.get_notifications().await.unwrap();
+    let second = client.load_more_notifications(&first).await.unwrap().unwrap();
+    let third = client.load_more_notifications(&second).await.unwrap();
+
+    assert_eq!(second.notifications[0].notification_type, NotificationType::Replied);
+    assert!(third.is_none());
+}
+
+#[tokio::test]
+async fn mark_notifications_read_single_and_all() {
+    let server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path("/notifications/mark-read.json"))
+        .and(body_json(json!({ "id": 7 })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "success": "OK" })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/notifications/mark-read.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "success": "OK" })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    client.mark_notifications_read(Some(7)).await.unwrap();
+    client.mark_notifications_read(None).await.unwrap();
+}
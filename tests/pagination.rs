@@ -0,0 +1,58 @@
+mod common;
+
+use common::topic;
+use discourse_api_rs::DiscourseClient;
+use futures_util::{StreamExt, TryStreamExt};
+use serde_json::json;
+use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn page(ids: &[u64], more_topics_url: Option<&str>) -> serde_json::Value {
+    json!({
+        "users": [],
+        "topic_list": {
+            "per_page": 2,
+            "more_topics_url": more_topics_url,
+            "topics": ids.iter().map(|id| topic(*id)).collect::<Vec<_>>()
+        }
+    })
+}
+
+async fn mount_pages(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/latest.json"))
+        .and(query_param_is_missing("page"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page(&[1, 2], Some("/latest?page=1"))))
+        .mount(server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/latest.json"))
+        .and(query_param("page", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page(&[3], None)))
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn latest_stream_follows_more_topics_url() {
+    let server = MockServer::start().await;
+    mount_pages(&server).await;
+    let client = DiscourseClient::new(server.uri());
+
+    let topics: Vec<_> = client.latest_stream().try_collect().await.unwrap();
+
+    let ids: Vec<u64> = topics.iter().map(|topic| topic.id.0).collect();
+    assert_eq!(ids, vec![1, 2, 3]);
+}
+
+#[tokio::test]
+async fn latest_stream_stops_fetching_when_dropped() {
+    let server = MockServer::start().await;
+    mount_pages(&server).await;
+    let client = DiscourseClient::new(server.uri());
+
+    let topics: Vec<_> = client.latest_stream().take(2).collect().await;
+
+    assert_eq!(topics.len(), 2);
+    assert_eq!(server.received_requests().await.unwrap().len(), 1);
+}
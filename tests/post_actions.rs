@@ -0,0 +1,53 @@
+mod common;
+
+use discourse_api_rs::{DiscourseClient, PostActionType, PostId};
+use serde_json::json;
+use wiremock::matchers::{body_json, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn act_on_post_sends_action_type_and_message() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/post_actions.json"))
+        .and(body_json(json!({
+            "id": 5,
+            "post_action_type_id": 7,
+            "message": "Please take a look"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::post(5, 1)))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let post = client
+        .act_on_post(PostId(5), PostActionType::NotifyModerators, Some("Please take a look"))
+        .await
+        .unwrap();
+
+    assert_eq!(post.id, PostId(5));
+}
+
+#[tokio::test]
+async fn unlike_post_undoes_like_action() {
+    let server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path("/post_actions/5.json"))
+        .and(query_param("post_action_type_id", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::post(5, 1)))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    client.unlike_post(PostId(5)).await.unwrap();
+}
+
+#[test]
+fn custom_action_types_round_trip() {
+    assert_eq!(PostActionType::from(2), PostActionType::Like);
+    assert_eq!(PostActionType::from(42), PostActionType::Custom(42));
+    assert_eq!(PostActionType::Custom(42).id(), 42);
+    assert!(PostActionType::Spam.is_flag());
+}
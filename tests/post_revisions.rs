@@ -0,0 +1,97 @@
+mod common;
+
+use discourse_api_rs::{CategoryId, DiscourseClient, PostId};
+use serde_json::json;
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn update_post_sends_edit_reason_and_returns_post() {
+    let mut updated = common::post(5, 1);
+    updated["raw"] = json!("Fixed typo");
+    let server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path("/posts/5.json"))
+        .and(body_json(json!({
+            "post": { "raw": "Fixed typo", "edit_reason": "typo" }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "post": updated })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let post = client.update_post(PostId(5), "Fixed typo", Some("typo")).await.unwrap();
+
+    assert_eq!(post.raw.as_deref(), Some("Fixed typo"));
+}
+
+#[tokio::test]
+async fn get_post_revision_parses_diffs() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/posts/5/revisions/2.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "created_at": "2024-01-02T00:00:00.000Z",
+            "post_id": 5,
+            "previous_hidden": false,
+            "current_hidden": false,
+            "first_revision": 1,
+            "previous_revision": 1,
+            "current_revision": 2,
+            "next_revision": null,
+            "last_revision": 2,
+            "current_version": 2,
+            "version_count": 2,
+            "username": "alice",
+            "display_username": "alice",
+            "avatar_template": "/letter_avatar/alice/{size}/1.png",
+            "edit_reason": "typo",
+            "body_changes": {
+                "inline": "<div class=\"inline-diff\"><p>Fixed <del>tpyo</del><ins>typo</ins></p></div>",
+                "side_by_side": "<div class=\"revision-content\"></div>",
+                "side_by_side_markdown": "<table class=\"markdown\"></table>"
+            },
+            "title_changes": { "inline": "<div class=\"inline-diff\">New title</div>" },
+            "category_id_changes": { "previous": 5, "current": 6 },
+            "tags_changes": { "previous": ["ops"], "current": ["ops", "backups"] },
+            "wiki": false,
+            "can_edit": true
+        })))
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let revision = client.get_post_revision(PostId(5), 2).await.unwrap();
+
+    assert_eq!(revision.previous_revision, Some(1));
+    let body = revision.body_changes.unwrap();
+    assert!(body.inline.contains("<ins>typo</ins>"));
+    assert!(body.side_by_side_markdown.is_some());
+    let category = revision.category_id_changes.unwrap();
+    assert_eq!((category.previous, category.current), (Some(CategoryId(5)), Some(CategoryId(6))));
+    assert_eq!(revision.tags_changes.unwrap().current, vec!["ops", "backups"]);
+}
+
+#[tokio::test]
+async fn revert_and_hide_revisions() {
+    let server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path("/posts/5/revisions/1/revert"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "post": common::post(5, 1) })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/posts/5/revisions/2/hide"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let post = client.revert_post_to_revision(PostId(5), 1).await.unwrap();
+    client.hide_post_revision(PostId(5), 2).await.unwrap();
+
+    assert_eq!(post.id, PostId(5));
+}
@@ -0,0 +1,85 @@
+mod common;
+
+use discourse_api_rs::{DiscourseClient, TopicId};
+use futures_util::TryStreamExt;
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, Request, Respond, ResponseTemplate};
+
+const TOPIC_ID: u64 = 7;
+
+fn post(id: u64) -> serde_json::Value {
+    common::post(id, TOPIC_ID)
+}
+
+/// Answers `/t/{id}/posts.json?post_ids[]=...` with the requested posts.
+struct PostsById;
+
+impl Respond for PostsById {
+    fn respond(&self, request: &Request) -> ResponseTemplate {
+        let posts: Vec<_> = request
+            .url
+            .query_pairs()
+            .filter(|(key, _)| key == "post_ids[]")
+            .map(|(_, value)| post(value.parse().unwrap()))
+            .collect();
+        assert!(posts.len() <= 20, "requested more posts than one chunk");
+        ResponseTemplate::new(200).set_body_json(json!({
+            "id": TOPIC_ID,
+            "post_stream": { "posts": posts }
+        }))
+    }
+}
+
+async fn mount_topic(server: &MockServer, post_count: u64) {
+    let first_page: Vec<_> = (1..=post_count.min(20)).map(post).collect();
+    Mock::given(method("GET"))
+        .and(path(format!("/t/{TOPIC_ID}.json")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": TOPIC_ID,
+            "title": "Long topic",
+            "posts_count": post_count,
+            "category_id": 1,
+            "post_stream": {
+                "posts": first_page,
+                "stream": (1..=post_count).collect::<Vec<_>>()
+            }
+        })))
+        .mount(server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/t/{TOPIC_ID}/posts.json")))
+        .respond_with(PostsById)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn get_full_topic_loads_every_post_in_order() {
+    let server = MockServer::start().await;
+    mount_topic(&server, 65).await;
+    let client = DiscourseClient::new(server.uri());
+
+    let topic = client.get_full_topic(TopicId(TOPIC_ID)).await.unwrap();
+
+    let ids: Vec<u64> = topic.post_stream.posts.iter().map(|post| post.id.0).collect();
+    assert_eq!(ids, (1..=65).collect::<Vec<_>>());
+    // One topic request plus three chunks for posts 21..=65.
+    assert_eq!(server.received_requests().await.unwrap().len(), 4);
+}
+
+#[tokio::test]
+async fn topic_posts_stream_yields_posts_in_order() {
+    let server = MockServer::start().await;
+    mount_topic(&server, 45).await;
+    let client = DiscourseClient::new(server.uri());
+
+    let posts: Vec<_> = client
+        .topic_posts_stream(TopicId(TOPIC_ID), 2)
+        .try_collect()
+        .await
+        .unwrap();
+
+    let ids: Vec<u64> = posts.iter().map(|post| post.id.0).collect();
+    assert_eq!(ids, (1..=45).collect::<Vec<_>>());
+}
@@ -0,0 +1,193 @@
+mod common;
+
+use discourse_api_rs::{DiscourseClient, PostActionType, PostId, PostType, TopicId};
+use serde_json::json;
+use wiremock::matchers::{body_json, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn set_post_type_sends_numeric_type_and_refetches() {
+    let mut whisper = common::post(5, 1);
+    whisper["post_type"] = json!(4);
+    let server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path("/posts/5/post_type"))
+        .and(body_json(json!({ "post_type": 4 })))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/posts/5.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(whisper))
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let post = client.set_post_type(PostId(5), PostType::Whisper).await.unwrap();
+
+    assert_eq!(post.post_type, PostType::Whisper);
+}
+
+#[tokio::test]
+async fn change_post_owner_uses_the_posts_topic() {
+    let mut reassigned = common::post(5, 9);
+    reassigned["username"] = json!("bob");
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/posts/5.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(reassigned))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/t/9/change-owner.json"))
+        .and(body_json(json!({ "username": "bob", "post_ids": [5] })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "success": "OK" })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let post = client.change_post_owner(PostId(5), "bob").await.unwrap();
+
+    assert_eq!(post.username, "bob");
+}
+
+#[tokio::test]
+async fn force_destroy_returns_nothing_to_refetch() {
+    let server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path("/posts/5.json"))
+        .and(query_param("force_destroy", "true"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let post = client.delete_post_with_options(PostId(5), true).await.unwrap();
+
+    assert!(post.is_none());
+}
+
+#[tokio::test]
+async fn get_post_parses_replies_actions_and_polls() {
+    let mut full = common::post(5, 1);
+    full["name"] = json!("Alice");
+    full["reply_to_post_number"] = json!(2);
+    full["reply_to_user"] = json!({ "username": "bob", "name": "Bob", "avatar_template": "/a.png" });
+    full["actions_summary"] = json!([
+        { "id": 2, "count": 3, "acted": true, "can_undo": true },
+        { "id": 8, "can_act": true }
+    ]);
+    full["link_counts"] = json!([
+        { "url": "https://example.com", "internal": false, "reflection": false, "clicks": 4 }
+    ]);
+    full["polls"] = json!([{
+        "name": "poll",
+        "type": "regular",
+        "status": "open",
+        "voters": 2,
+        "options": [{ "id": "abc", "html": "Yes", "votes": 2 }]
+    }]);
+    full["version"] = json!(3);
+    full["can_edit"] = json!(true);
+    full["wiki"] = json!(true);
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/posts/5.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(full))
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let post = client.get_post(PostId(5)).await.unwrap();
+
+    assert_eq!(post.reply_to_user.as_ref().unwrap().username, "bob");
+    assert_eq!(post.action_count(PostActionType::Like), 3);
+    assert_eq!(post.action_count(PostActionType::Spam), 0);
+    assert_eq!(post.link_counts[0].clicks, 4);
+    assert_eq!(post.polls[0].options[0].votes, Some(2));
+    assert_eq!(post.version, Some(3));
+    assert!(post.can_edit && post.wiki && !post.hidden);
+}
+
+#[tokio::test]
+async fn delete_succeeds_when_deleted_post_is_no_longer_visible() {
+    let server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path("/posts/5.json"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/posts/5.json"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "errors": ["The requested URL or resource could not be found."],
+            "error_type": "not_found"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    client.delete_post(PostId(5)).await.unwrap();
+    let post = client.delete_post_with_options(PostId(5), false).await.unwrap();
+
+    assert!(post.is_none());
+}
+
+#[tokio::test]
+async fn recover_post_parses_the_recovered_post() {
+    let server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path("/posts/5/recover.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::post(5, 1)))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/posts/5.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(common::post(5, 1)))
+        .expect(0)
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let post = client.recover_post(PostId(5)).await.unwrap();
+
+    assert_eq!(post.id, PostId(5));
+}
+
+#[tokio::test]
+async fn create_post_parses_post_type() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/posts.json"))
+        .and(body_json(json!({ "raw": "Thanks!", "topic_id": 1 })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 6,
+            "name": null,
+            "username": "alice",
+            "avatar_template": "/letter_avatar/alice/{size}/1.png",
+            "created_at": "2024-01-01T00:00:00.000Z",
+            "cooked": "<p>Thanks!</p>",
+            "post_number": 2,
+            "post_type": 1,
+            "updated_at": "2024-01-01T00:00:00.000Z",
+            "reply_count": 0,
+            "reply_to_post_number": null,
+            "quote_count": 0,
+            "topic_id": 1,
+            "topic_slug": "topic-1"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let post = client.create_post(TopicId(1), "Thanks!", None).await.unwrap();
+
+    assert_eq!(post.post_type, PostType::Regular);
+}
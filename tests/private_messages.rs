@@ -0,0 +1,68 @@
+mod common;
+
+use discourse_api_rs::{DiscourseClient, PrivateMessageFolder, TopicId};
+use futures_util::TryStreamExt;
+use serde_json::json;
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn send_private_message_targets_recipients() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/posts.json"))
+        .and(body_partial_json(json!({
+            "archetype": "private_message",
+            "target_recipients": "alice,support"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 90,
+            "name": null,
+            "username": "bot",
+            "avatar_template": "/letter_avatar/bot/{size}/1.png",
+            "created_at": "2024-01-01T00:00:00.000Z",
+            "cooked": "<p>Hello</p>",
+            "post_number": 1,
+            "post_type": 1,
+            "updated_at": "2024-01-01T00:00:00.000Z",
+            "reply_count": 0,
+            "reply_to_post_number": null,
+            "quote_count": 0,
+            "topic_id": 12,
+            "topic_slug": "welcome"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let post = client
+        .send_private_message(&["alice", "support"], "Welcome", "Hello")
+        .await
+        .unwrap();
+
+    assert_eq!(post.topic_id, TopicId(12));
+}
+
+#[tokio::test]
+async fn private_messages_stream_reads_sent_folder() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/topics/private-messages-sent/bot.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "users": [],
+            "topic_list": { "topics": [common::topic(12)] }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let topics: Vec<_> = client
+        .private_messages_stream("bot", PrivateMessageFolder::Sent)
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(topics.len(), 1);
+}
@@ -0,0 +1,116 @@
+use discourse_api_rs::{DiscourseClient, RetryPolicy};
+use serde_json::json;
+use std::time::Duration;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn latest_body() -> serde_json::Value {
+    json!({
+        "users": [],
+        "topic_list": { "topics": [] }
+    })
+}
+
+fn rate_limited_body(wait_seconds: f64) -> serde_json::Value {
+    json!({
+        "errors": ["You’ve performed this action too many times. Please wait a few seconds before trying again."],
+        "error_type": "rate_limit",
+        "extras": { "wait_seconds": wait_seconds, "time_left": "0 seconds" }
+    })
+}
+
+#[tokio::test]
+async fn retries_after_retry_after_header() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/latest.json"))
+        .respond_with(
+            ResponseTemplate::new(429)
+                .insert_header("Retry-After", "0")
+                .set_body_json(rate_limited_body(0.0)),
+        )
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/latest.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(latest_body()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = DiscourseClient::new(server.uri());
+    let latest = client.get_latest().await.expect("request should be retried");
+
+    assert!(latest.topic_list.topics.is_empty());
+}
+
+#[tokio::test]
+async fn retries_using_wait_seconds_from_body() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/latest.json"))
+        .respond_with(ResponseTemplate::new(429).set_body_json(rate_limited_body(0.01)))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/latest.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(latest_body()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = DiscourseClient::new(server.uri());
+
+    assert!(client.get_latest().await.is_ok());
+}
+
+#[tokio::test]
+async fn gives_up_when_retry_budget_is_spent() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/latest.json"))
+        .respond_with(
+            ResponseTemplate::new(429)
+                .insert_header("Retry-After", "0")
+                .set_body_json(rate_limited_body(0.0)),
+        )
+        .expect(3)
+        .mount(&server)
+        .await;
+
+    let client = DiscourseClient::new(server.uri()).with_retry_policy(RetryPolicy {
+        max_retries: 2,
+        max_wait: Duration::from_millis(10),
+    });
+
+    assert!(client.get_latest().await.unwrap_err().is_rate_limited());
+}
+
+#[tokio::test]
+async fn gives_up_when_server_asks_to_wait_longer_than_max_wait() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/latest.json"))
+        .respond_with(
+            ResponseTemplate::new(429)
+                .insert_header("Retry-After", "30")
+                .set_body_json(rate_limited_body(30.0)),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = DiscourseClient::new(server.uri()).with_retry_policy(RetryPolicy {
+        max_retries: 2,
+        max_wait: Duration::from_millis(10),
+    });
+
+    let error = client.get_latest().await.unwrap_err();
+
+    assert!(error.is_rate_limited());
+    assert_eq!(error.wait_seconds(), Some(30.0));
+}
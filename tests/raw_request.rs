@@ -0,0 +1,133 @@
+mod common;
+
+use discourse_api_rs::{DiscourseClient, Error, LatestResponse, Method, ParseMode};
+use serde::Deserialize;
+use serde_json::json;
+use wiremock::matchers::{body_json, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[derive(Debug, Deserialize)]
+struct BadgesResponse {
+    badges: Vec<Badge>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Badge {
+    id: u64,
+    name: String,
+}
+
+#[tokio::test]
+async fn request_sends_auth_query_and_body() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/admin/badges.json"))
+        .and(query_param("show", "all"))
+        .and(header("Api-Key", "secret"))
+        .and(header("Api-Username", "system"))
+        .and(body_json(json!({ "name": "Helper" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "badges": [{ "id": 101, "name": "Helper" }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::with_api_key(server.uri(), "secret", "system");
+
+    let response: BadgesResponse = client
+        .request(
+            Method::POST,
+            "/admin/badges.json",
+            &[("show", "all")],
+            Some(&json!({ "name": "Helper" })),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.badges[0].id, 101);
+    assert_eq!(response.badges[0].name, "Helper");
+}
+
+#[tokio::test]
+async fn request_reads_empty_body_as_null() {
+    let server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path("/admin/badges/101.json"))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    client
+        .request::<()>(Method::DELETE, "/admin/badges/101.json", &[], None)
+        .await
+        .unwrap();
+    let value = client
+        .request_json(Method::DELETE, "/admin/badges/101.json", &[], None)
+        .await
+        .unwrap();
+
+    assert!(value.is_null());
+}
+
+#[tokio::test]
+async fn request_retries_and_maps_errors() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/admin/badges.json"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/admin/badges.json"))
+        .respond_with(ResponseTemplate::new(403).set_body_json(json!({
+            "errors": ["You are not permitted to view the requested resource."],
+            "error_type": "invalid_access"
+        })))
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let error = client
+        .request_json(Method::GET, "/admin/badges.json", &[], None)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, Error::InvalidAccess { .. }));
+    assert_eq!(server.received_requests().await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn request_applies_strict_parse_mode() {
+    let mut topic = common::topic(1);
+    topic.as_object_mut().unwrap().remove("has_summary");
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/latest.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "users": [],
+            "topic_list": { "topics": [topic] }
+        })))
+        .mount(&server)
+        .await;
+    let lenient = DiscourseClient::new(server.uri());
+    let strict = DiscourseClient::new(server.uri()).with_parse_mode(ParseMode::Strict);
+
+    let latest: LatestResponse = lenient
+        .request(Method::GET, "/latest.json", &[], None)
+        .await
+        .unwrap();
+    let error = strict
+        .request::<LatestResponse>(Method::GET, "/latest.json", &[], None)
+        .await
+        .unwrap_err();
+
+    assert!(!latest.topic_list.topics[0].has_summary);
+    match error {
+        Error::Json(error) => assert!(error
+            .to_string()
+            .contains("topic_list.topics[0].has_summary")),
+        other => panic!("expected a JSON error, got {:?}", other),
+    }
+}
@@ -0,0 +1,69 @@
+use discourse_api_rs::{DiscourseClient, PostId, SearchIn, SearchQuery, TopicId};
+use serde_json::json;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[test]
+fn renders_advanced_filters() {
+    let query = SearchQuery::new("")
+        .subcategory("support", "installs")
+        .all_tags(&["docker", "nginx"])
+        .within(SearchIn::Bookmarks)
+        .after("2024-01-01")
+        .before("2024-02-01")
+        .filter("min_posts:5");
+
+    assert_eq!(
+        query.to_string(),
+        "#support:installs tags:docker+nginx in:bookmarks after:2024-01-01 before:2024-02-01 min_posts:5"
+    );
+}
+
+#[tokio::test]
+async fn search_sends_query_and_parses_results() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search.json"))
+        .and(query_param("q", "backup @sam"))
+        .and(query_param("page", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "posts": [{
+                "id": 11,
+                "name": "Sam",
+                "username": "sam",
+                "avatar_template": "/user_avatar/sam/{size}/1.png",
+                "created_at": "2024-01-01T00:00:00.000Z",
+                "like_count": 2,
+                "blurb": "how to restore a backup",
+                "post_number": 1,
+                "topic_id": 5
+            }],
+            "topics": [{ "id": 5, "title": "Backups", "slug": "backups", "tags": [{ "id": 1, "name": "ops" }] }],
+            "users": [],
+            "categories": [],
+            "tags": [],
+            "grouped_search_result": {
+                "term": "backup @sam",
+                "more_posts": null,
+                "post_ids": [11],
+                "user_ids": [],
+                "category_ids": [],
+                "tag_ids": [],
+                "group_ids": [],
+                "can_create_topic": true
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let result = client
+        .search(&SearchQuery::new("backup").user("sam"))
+        .await
+        .unwrap();
+
+    assert_eq!(result.posts[0].topic_id, TopicId(5));
+    assert_eq!(result.topics[0].tags, vec!["ops"]);
+    assert_eq!(result.grouped_search_result.unwrap().post_ids, vec![PostId(11)]);
+}
@@ -0,0 +1,35 @@
+mod common;
+
+use discourse_api_rs::DiscourseClient;
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn topic_tags_accept_names_and_objects() {
+    let mut with_names = common::topic(1);
+    with_names["tags"] = json!(["howto", "rust"]);
+    let mut with_objects = common::topic(2);
+    with_objects["tags"] = json!([
+        { "id": 4, "name": "howto", "slug": "howto" },
+        { "id": 9, "name": "rust", "slug": "rust" }
+    ]);
+    let without_tags = common::topic(3);
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/tag/howto.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "users": [],
+            "topic_list": { "topics": [with_names, with_objects, without_tags] }
+        })))
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let topics = client.get_tag_topics("howto").await.unwrap().topic_list.topics;
+
+    assert_eq!(topics[0].tags, vec!["howto", "rust"]);
+    assert_eq!(topics[1].tags, vec!["howto", "rust"]);
+    assert!(topics[2].tags.is_empty());
+}
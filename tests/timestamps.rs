@@ -0,0 +1,59 @@
+#![cfg(feature = "chrono")]
+
+mod common;
+
+use chrono::{TimeZone, Utc};
+use discourse_api_rs::{DiscourseClient, Post, Timestamp};
+use serde_json::json;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn topic_timestamps_parse_as_datetimes() {
+    let mut topic = common::topic(1);
+    topic["last_posted_at"] = json!("2024-01-03T10:15:30.250Z");
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/latest.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "users": [],
+            "topic_list": { "topics": [topic] }
+        })))
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let latest = client.get_latest().await.unwrap();
+    let topic = &latest.topic_list.topics[0];
+
+    let created_at = topic.created_at.datetime().unwrap();
+    assert_eq!(created_at, Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap());
+    assert!(topic.last_posted_at.as_ref().unwrap().datetime().unwrap() > created_at);
+}
+
+#[test]
+fn timestamps_round_trip_through_serde() {
+    let mut source = common::post(5, 1);
+    source["created_at"] = json!("2024-01-01T08:30:00+02:00");
+    let post: Post = serde_json::from_value(source).unwrap();
+
+    let value = serde_json::to_value(&post).unwrap();
+
+    assert_eq!(value["created_at"], "2024-01-01T08:30:00+02:00");
+    assert_eq!(
+        post.created_at.datetime(),
+        Some(Utc.with_ymd_and_hms(2024, 1, 1, 6, 30, 0).unwrap())
+    );
+    let again: Post = serde_json::from_value(value).unwrap();
+    assert_eq!(again.created_at, post.created_at);
+}
+
+#[test]
+fn timestamps_convert_from_datetimes() {
+    let datetime = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+
+    let timestamp = Timestamp::from(datetime);
+
+    assert_eq!(timestamp.as_str(), "2024-06-01T00:00:00.000Z");
+    assert_eq!(timestamp.datetime(), Some(datetime));
+}
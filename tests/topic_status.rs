@@ -0,0 +1,109 @@
+use discourse_api_rs::{DiscourseClient, Timestamp, TopicId};
+use serde_json::json;
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// Mounts the status update expecting `body`, and a topic that reports
+/// `state` once it is refetched.
+async fn mount_status_change(
+    server: &MockServer,
+    body: serde_json::Value,
+    state: serde_json::Value,
+) {
+    Mock::given(method("PUT"))
+        .and(path("/t/7/status.json"))
+        .and(body_json(body))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "success": "OK" })))
+        .expect(1)
+        .mount(server)
+        .await;
+    let mut topic = json!({
+        "post_stream": { "posts": [], "stream": [] },
+        "id": 7,
+        "title": "Backups",
+        "category_id": 5,
+        "closed": false,
+        "archived": false,
+        "pinned": false,
+        "pinned_globally": false,
+        "pinned_until": null,
+        "visible": true
+    });
+    for (key, value) in state.as_object().unwrap() {
+        topic[key] = value.clone();
+    }
+    Mock::given(method("GET"))
+        .and(path("/t/7.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(topic))
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn close_topic_returns_closed_topic() {
+    let server = MockServer::start().await;
+    mount_status_change(
+        &server,
+        json!({ "status": "closed", "enabled": true }),
+        json!({ "closed": true }),
+    )
+    .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let topic = client.close_topic(TopicId(7)).await.unwrap();
+
+    assert!(topic.closed);
+}
+
+#[tokio::test]
+async fn pin_topic_sends_until_and_returns_pinned_topic() {
+    let server = MockServer::start().await;
+    mount_status_change(
+        &server,
+        json!({ "status": "pinned", "enabled": true, "until": "2024-06-01T00:00:00Z" }),
+        json!({ "pinned": true, "pinned_until": "2024-06-01T00:00:00.000Z" }),
+    )
+    .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let topic = client
+        .pin_topic(TopicId(7), Some(&Timestamp::from("2024-06-01T00:00:00Z")))
+        .await
+        .unwrap();
+
+    assert!(topic.pinned);
+    assert!(topic.pinned_until.is_some());
+}
+
+#[tokio::test]
+async fn unlist_topic_returns_invisible_topic() {
+    let server = MockServer::start().await;
+    mount_status_change(
+        &server,
+        json!({ "status": "visible", "enabled": false }),
+        json!({ "visible": false }),
+    )
+    .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let topic = client.unlist_topic(TopicId(7)).await.unwrap();
+
+    assert!(!topic.visible);
+}
+
+#[tokio::test]
+async fn archive_topic_returns_archived_topic() {
+    let server = MockServer::start().await;
+    mount_status_change(
+        &server,
+        json!({ "status": "archived", "enabled": true }),
+        json!({ "archived": true }),
+    )
+    .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let topic = client.archive_topic(TopicId(7)).await.unwrap();
+
+    assert!(topic.archived);
+}
@@ -0,0 +1,91 @@
+use discourse_api_rs::{CategoryId, DiscourseClient, PostDestination, PostId, TopicId};
+use serde_json::json;
+use wiremock::matchers::{body_json, body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn move_posts_returns_the_new_destination_topic() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/t/10/move-posts.json"))
+        .and(body_partial_json(json!({
+            "post_ids": [3, 4],
+            "title": "Split out discussion",
+            "category_id": 2
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "success": "OK",
+            "url": "/t/split-out-discussion/42"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/t/42.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 42,
+            "title": "Split out discussion",
+            "category_id": 2,
+            "post_stream": { "posts": [], "stream": [3, 4] }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let topic = client
+        .move_posts(
+            TopicId(10),
+            &[PostId(3), PostId(4)],
+            PostDestination::NewTopic {
+                title: "Split out discussion".to_string(),
+                category_id: Some(CategoryId(2)),
+                tags: Vec::new(),
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(topic.id, TopicId(42));
+    assert_eq!(topic.post_stream.stream, vec![PostId(3), PostId(4)]);
+}
+
+#[tokio::test]
+async fn update_topic_sends_changes_and_parses_basic_topic() {
+    let server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path("/t/-/10.json"))
+        .and(body_json(json!({
+            "title": "Renamed topic",
+            "category_id": 2,
+            "tags": ["rust", "api"]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "basic_topic": {
+                "id": 10,
+                "title": "Renamed topic",
+                "fancy_title": "Renamed topic",
+                "slug": "renamed-topic",
+                "posts_count": 4
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let topic = client
+        .update_topic(
+            TopicId(10),
+            Some("Renamed topic"),
+            Some(CategoryId(2)),
+            Some(&["rust", "api"]),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(topic.id, TopicId(10));
+    assert_eq!(topic.title, "Renamed topic");
+    assert_eq!(topic.slug, "renamed-topic");
+    assert_eq!(topic.posts_count, Some(4));
+}
@@ -0,0 +1,62 @@
+use discourse_api_rs::{DiscourseClient, UploadType};
+use serde_json::json;
+use wiremock::matchers::{body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn upload_file_posts_multipart_and_renders_markdown() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/uploads.json"))
+        .and(body_string_contains("name=\"type\"\r\n\r\ncomposer"))
+        .and(body_string_contains("filename=\"diagram.png\""))
+        .and(body_string_contains("PNGDATA"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 17,
+            "url": "/uploads/default/original/1X/abc.png",
+            "short_url": "upload://abc.png",
+            "original_filename": "diagram.png",
+            "filesize": 7,
+            "width": 690,
+            "height": 388,
+            "extension": "png"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let upload = client
+        .upload_file(b"PNGDATA".to_vec(), "diagram.png", UploadType::Composer)
+        .await
+        .unwrap();
+
+    assert_eq!(upload.markdown(), "![diagram|690x388](upload://abc.png)");
+}
+
+#[tokio::test]
+async fn attachments_render_as_links() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/uploads.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 18,
+            "url": "/uploads/default/original/1X/def.pdf",
+            "short_url": "upload://def.pdf",
+            "original_filename": "report.pdf",
+            "filesize": 12345,
+            "human_filesize": "12.1 KB",
+            "width": null,
+            "height": null
+        })))
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let upload = client
+        .upload_file(b"%PDF".to_vec(), "report.pdf", UploadType::Composer)
+        .await
+        .unwrap();
+
+    assert_eq!(upload.markdown(), "[report.pdf|attachment](upload://def.pdf) (12.1 KB)");
+}
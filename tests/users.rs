@@ -0,0 +1,172 @@
+use discourse_api_rs::{
+    DirectoryOrder, DirectoryPeriod, DiscourseClient, TopicId, UserActionType, UserId,
+};
+use serde_json::json;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn get_user_actions_filters_by_type() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/user_actions.json"))
+        .and(query_param("username", "alice"))
+        .and(query_param("filter", "4,5"))
+        .and(query_param("offset", "30"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "user_actions": [
+                {
+                    "action_type": 4,
+                    "created_at": "2024-01-01T00:00:00.000Z",
+                    "topic_id": 3,
+                    "post_number": 1,
+                    "username": "alice"
+                },
+                {
+                    "action_type": 99,
+                    "created_at": "2024-01-02T00:00:00.000Z"
+                }
+            ]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let actions = client
+        .get_user_actions(
+            "alice",
+            &[UserActionType::NewTopic, UserActionType::Reply],
+            30,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(actions[0].action_type, UserActionType::NewTopic);
+    assert_eq!(actions[1].action_type, UserActionType::Other(99));
+}
+
+#[tokio::test]
+async fn get_user_parses_groups_and_custom_fields() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/u/alice.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "user": {
+                "id": 3,
+                "username": "alice",
+                "name": "Alice",
+                "avatar_template": "/letter_avatar/alice/{size}/1.png",
+                "trust_level": 2,
+                "admin": false,
+                "moderator": true,
+                "groups": [
+                    { "id": 41, "name": "staff", "display_name": "Staff", "automatic": true },
+                    { "id": 42, "name": "donors", "automatic": false, "user_count": 12 }
+                ],
+                "custom_fields": { "pronouns": "they/them" },
+                "user_fields": { "1": "Berlin" }
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let user = client.get_user("alice").await.unwrap();
+
+    assert_eq!(user.id, UserId(3));
+    assert_eq!(user.trust_level, 2);
+    assert!(user.moderator);
+    assert_eq!(user.groups.len(), 2);
+    assert_eq!(user.groups[0].name, "staff");
+    assert!(user.groups[0].automatic);
+    assert_eq!(user.groups[1].user_count, 12);
+    assert_eq!(user.custom_fields["pronouns"], "they/them");
+    assert_eq!(user.user_fields.unwrap()["1"], "Berlin");
+}
+
+#[tokio::test]
+async fn get_user_summary_parses_stats_and_topics() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/u/alice/summary.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "topics": [
+                { "id": 7, "title": "Backups", "fancy_title": "Backups", "slug": "backups", "posts_count": 3 }
+            ],
+            "user_summary": {
+                "likes_given": 5,
+                "likes_received": 8,
+                "days_visited": 20,
+                "post_count": 14,
+                "time_read": 3600,
+                "can_see_summary_stats": true,
+                "topic_ids": [7],
+                "replies": [
+                    { "topic_id": 7, "post_number": 2, "like_count": 1, "created_at": "2024-01-01T00:00:00.000Z" }
+                ],
+                "most_liked_by_users": [
+                    { "id": 4, "username": "bob", "name": "Bob", "avatar_template": "/letter_avatar/bob/{size}/1.png", "count": 3 }
+                ]
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let summary = client.get_user_summary("alice").await.unwrap();
+
+    assert_eq!(summary.user_summary.likes_received, 8);
+    assert_eq!(summary.user_summary.time_read, 3600);
+    assert_eq!(summary.user_summary.topic_ids, vec![TopicId(7)]);
+    assert_eq!(summary.user_summary.replies[0].post_number, 2);
+    assert_eq!(summary.user_summary.most_liked_by_users[0].username, "bob");
+    assert_eq!(summary.topics[0].slug, "backups");
+}
+
+#[tokio::test]
+async fn get_directory_items_parses_items_and_meta() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/directory_items.json"))
+        .and(query_param("period", "weekly"))
+        .and(query_param("order", "likes_received"))
+        .and(query_param("page", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "directory_items": [{
+                "id": 3,
+                "likes_received": 12,
+                "likes_given": 4,
+                "post_count": 9,
+                "days_visited": 6,
+                "user": {
+                    "id": 3,
+                    "username": "alice",
+                    "name": "Alice",
+                    "avatar_template": "/letter_avatar/alice/{size}/1.png"
+                }
+            }],
+            "meta": {
+                "total_rows_directory_items": 31,
+                "load_more_directory_items": "/directory_items?order=likes_received&page=2&period=weekly"
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = DiscourseClient::new(server.uri());
+
+    let directory = client
+        .get_directory_items(DirectoryPeriod::Weekly, DirectoryOrder::LikesReceived, 1)
+        .await
+        .unwrap();
+
+    let item = &directory.directory_items[0];
+    assert_eq!(item.id, UserId(3));
+    assert_eq!(item.likes_received, 12);
+    assert_eq!(item.user.username, "alice");
+    assert_eq!(directory.meta.total_rows_directory_items, 31);
+    assert!(directory.meta.load_more_directory_items.is_some());
+}